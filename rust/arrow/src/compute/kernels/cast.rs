@@ -37,36 +37,395 @@
 
 use std::sync::Arc;
 
+use chrono::{NaiveTime, Timelike};
+use lazy_static::lazy_static;
+use serde_json::{Map, Number, Value};
+use unicode_normalization::UnicodeNormalization;
+
 use crate::array::*;
 use crate::array_data::ArrayData;
-use crate::buffer::Buffer;
+use crate::buffer::{Buffer, MutableBuffer};
 use crate::builder::*;
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
+use crate::record_batch::RecordBatch;
+use crate::util::bit_util;
+
+/// Options controlling how values are converted by `cast_with_options`
+///
+/// The default options give the same behavior as `cast`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CastOptions {
+    /// Pads `Utf8` values to the given width, counted in characters, using the given
+    /// character on the given side. Longer values are left untouched.
+    pub pad: Option<(usize, char, PadSide)>,
+    /// Removes this prefix from `Utf8` values that start with it
+    pub strip_prefix: Option<String>,
+    /// Removes this suffix from `Utf8` values that end with it
+    pub strip_suffix: Option<String>,
+    /// Reinterprets the bits of floats as integers of the same width, and vice versa,
+    /// instead of converting their values. The value buffer is shared, not copied.
+    pub bitcast: bool,
+    /// Formats integers in the given radix, between 2 and 36, when casting them to
+    /// `Utf8`. Digits above 9 are lowercase letters. Defaults to radix 10.
+    pub int_radix: Option<u32>,
+    /// How numeric casts handle values that do not fit into the target type
+    pub overflow: OverflowMode,
+    /// The decimal separator of `Utf8` values cast to numbers. With any separator other
+    /// than `.`, a `.` is read as a thousands separator and ignored, so `"1.234,56"`
+    /// parses as 1234.56 with a `,` separator.
+    pub decimal_separator: char,
+    /// Shifts `Date32` and `Date64` values by this many days when casting them to
+    /// their own type, e.g. to rebase dates stored relative to another epoch. Shifted
+    /// values that overflow become null.
+    pub date_epoch_shift_days: i32,
+    /// Checks that the offsets of `List` arrays are non-decreasing and within their
+    /// values before casting them, e.g. for arrays from untrusted sources
+    pub validate_offsets: bool,
+    /// Parses `Utf8` values with a trailing `%` as percentages when casting them to
+    /// floats, e.g. `"12.5%"` becomes 0.125. Integer casts leave these values null.
+    pub parse_percent: bool,
+    /// Replaces the strings that cast to `true` when casting `Utf8` to `Boolean`.
+    /// Strings are compared ignoring case.
+    pub bool_true_keywords: Option<Vec<String>>,
+    /// Replaces the strings that cast to `false` when casting `Utf8` to `Boolean`.
+    /// Strings are compared ignoring case.
+    pub bool_false_keywords: Option<Vec<String>>,
+    /// How float to integer casts round values that are not integral. Timestamp casts
+    /// to a coarser unit round the same way.
+    pub float_to_int_rounding: FloatToIntRounding,
+    /// Percent-decodes `Utf8` values, e.g. `%2F` becomes `/`. Invalid sequences are
+    /// left as they are.
+    pub url_decode: bool,
+    /// Fails `url_decode` on invalid sequences instead of leaving them as they are
+    pub url_decode_strict: bool,
+    /// Fails the cast before converting any values if the source array has nulls
+    pub forbid_source_nulls: bool,
+    /// The value of `Utf8` strings that can't be parsed when casting them to integers,
+    /// instead of null. A default that doesn't fit into the target type is null.
+    pub parse_default: Option<i64>,
+    /// The value of `Utf8` strings that can't be parsed when casting them to floats,
+    /// instead of null
+    pub parse_default_float: Option<f64>,
+    /// Replaces NaNs with the canonical quiet NaN when casting floats to their own type,
+    /// so that all NaNs have the same bits
+    pub canonicalize_nan: bool,
+    /// Parses `Utf8` values as JSON arrays when casting them to `List`, instead of
+    /// creating a list with 1 value per slot. Values that aren't JSON arrays are null.
+    pub parse_json: bool,
+    /// Normalizes `Utf8` values to the given Unicode normalization form, e.g. so that
+    /// composed and decomposed accents compare equal
+    pub unicode_normalize: Option<NormalizationForm>,
+    /// Separates groups of 3 digits with this character when casting integers to
+    /// `Utf8`, e.g. 1234567 becomes `"1,234,567"` with `,`
+    pub group_digits: Option<char>,
+    /// Left-pads integers with zeros to this width when casting them to `Utf8`, e.g.
+    /// 42 becomes `"00042"` with a width of 5. The sign of negative integers is kept in
+    /// front of the zeros, and counts towards the width.
+    pub int_zero_pad: Option<usize>,
+    /// Replaces invalid UTF-8 sequences in `Utf8` values with U+FFFD, e.g. for binary
+    /// data that was labeled as `Utf8`
+    pub utf8_lossy: bool,
+    /// Removes a pair of double quotes around `Utf8` values before parsing them as
+    /// numbers or booleans, e.g. `"\"42\""` parses as 42
+    pub strip_quotes: bool,
+    /// Casts integers to `Boolean` by testing this bit of each value, counting from
+    /// the least significant bit 0, instead of comparing values to 0
+    pub bool_bit: Option<u32>,
+    /// Formats floats with this many digits after the decimal point when casting them
+    /// to `Utf8`, instead of the fewest digits that round-trip
+    pub float_precision: Option<usize>,
+    /// Formats floats as percentages when casting them to `Utf8`, e.g. 0.125 becomes
+    /// `"12.5%"`. This is the inverse of `parse_percent`.
+    pub format_percent: bool,
+    /// Formats negative zero as `"0"` when casting floats to `Utf8`, instead of `"-0"`
+    pub normalize_negative_zero: bool,
+    /// Fails `Float64` to `Float32` casts on values that `Float32` can't represent
+    /// exactly, instead of rounding them
+    pub error_on_float_precision_loss: bool,
+    /// Converts the ASCII letters of `Utf8` values to the given case, leaving all other
+    /// characters untouched. This is cheaper than full Unicode case mapping.
+    pub ascii_case: Option<Case>,
+    /// Removes trailing characters that can't be part of a number, such as units, from
+    /// `Utf8` values before parsing them as numbers, e.g. `"42ms"` parses as 42. This
+    /// also strips the letters of values like `"1e"` or `"NaN"`.
+    pub strip_nonnumeric_suffix: bool,
+    /// Hints that numeric arrays cast to `Boolean` hold a single value, e.g. because an
+    /// upstream step produced a constant. The array is then checked with one scan, and
+    /// the output bitmap filled at once if the hint holds.
+    pub constant_hint: bool,
+}
+
+impl Default for CastOptions {
+    fn default() -> Self {
+        CastOptions {
+            pad: None,
+            strip_prefix: None,
+            strip_suffix: None,
+            bitcast: false,
+            int_radix: None,
+            overflow: OverflowMode::default(),
+            decimal_separator: '.',
+            date_epoch_shift_days: 0,
+            validate_offsets: false,
+            parse_percent: false,
+            bool_true_keywords: None,
+            bool_false_keywords: None,
+            float_to_int_rounding: FloatToIntRounding::default(),
+            url_decode: false,
+            url_decode_strict: false,
+            forbid_source_nulls: false,
+            parse_default: None,
+            parse_default_float: None,
+            canonicalize_nan: false,
+            parse_json: false,
+            unicode_normalize: None,
+            group_digits: None,
+            int_zero_pad: None,
+            utf8_lossy: false,
+            strip_quotes: false,
+            bool_bit: None,
+            float_precision: None,
+            format_percent: false,
+            normalize_negative_zero: false,
+            error_on_float_precision_loss: false,
+            ascii_case: None,
+            strip_nonnumeric_suffix: false,
+            constant_hint: false,
+        }
+    }
+}
+
+impl CastOptions {
+    /// Returns whether these options rewrite values of `data_type` even when the
+    /// source and target types are the same
+    fn rewrites_values(&self, data_type: &DataType) -> bool {
+        match data_type {
+            DataType::Utf8 => {
+                self.pad.is_some()
+                    || self.url_decode
+                    || self.utf8_lossy
+                    || self.ascii_case.is_some()
+                    || self.unicode_normalize.is_some()
+                    || self.strip_prefix.is_some()
+                    || self.strip_suffix.is_some()
+            }
+            DataType::Float32 | DataType::Float64 => self.canonicalize_nan,
+            DataType::Date32(_) | DataType::Date64(_) => self.date_epoch_shift_days != 0,
+            DataType::List(ref t) => self.rewrites_values(t),
+            DataType::Struct(ref fields) => {
+                fields.iter().any(|f| self.rewrites_values(f.data_type()))
+            }
+            _ => false,
+        }
+    }
+}
+
+/// How numeric casts handle values that are out of range for the target type
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OverflowMode {
+    /// Out of range values become null
+    Null,
+    /// Out of range values are clamped to the minimum or maximum of the target type.
+    /// `NaN` has no nearest bound and still becomes null.
+    Saturate,
+    /// The cast fails on the first out of range value
+    Error,
+}
+
+impl Default for OverflowMode {
+    fn default() -> Self {
+        OverflowMode::Null
+    }
+}
+
+/// How float to integer casts round values that are not integral
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FloatToIntRounding {
+    /// Rounds towards zero
+    Truncate,
+    /// Rounds to the nearest integer, and halfway cases to the nearest even integer,
+    /// e.g. 0.5 => 0, 1.5 => 2 and 2.5 => 2
+    Round,
+    /// Rounds towards negative infinity
+    Floor,
+    /// Rounds towards positive infinity
+    Ceil,
+}
+
+impl Default for FloatToIntRounding {
+    fn default() -> Self {
+        FloatToIntRounding::Truncate
+    }
+}
+
+/// A Unicode normalization form, see <https://unicode.org/reports/tr15/>
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NormalizationForm {
+    /// Canonical decomposition followed by canonical composition
+    Nfc,
+    /// Canonical decomposition
+    Nfd,
+    /// Compatibility decomposition followed by canonical composition
+    Nfkc,
+    /// Compatibility decomposition
+    Nfkd,
+}
+
+/// The case that `CastOptions::ascii_case` converts letters to
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Case {
+    Upper,
+    Lower,
+}
+
+/// The side of a `Utf8` value that padding is added to
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PadSide {
+    Left,
+    Right,
+}
 
 /// Cast array to provided data type
 ///
+/// This is `cast_with_options` with the default `CastOptions`.
+///
 /// Behavior:
 /// * Boolean to Utf8: `true` => '1', `false` => `0`
 /// * Utf8 to numeric: strings that can't be parsed to numbers return null, float strings
 ///   in integer casts return null
 /// * Numeric to boolean: 0 returns `false`, any other value returns `true`
-/// * List to List: the underlying data type is cast
+/// * Float to integer: values are truncated, so tiny values, including subnormals, return
+///   0, and values out of range of the integer type return null. The maximums of Int64
+///   and UInt64 are not representable as floats, and the nearest floats, e.g. `i64::MAX
+///   as f64` which is 2^63, are out of range
+/// * Utf8 to boolean: `true`, `t` and `1` return `true`, `false`, `f` and `0` return
+///   `false`, ignoring case. Other strings return null
+/// * List to List: the underlying data type is cast, e.g. `List(Utf8)` values are parsed
+///   like Utf8 values
 /// * Primitive to List: a list array with 1 value per slot is created
+/// * Utf8 to List: with `parse_json`, values are parsed as JSON arrays whose elements are
+///   cast like Utf8 values, e.g. `"[1,2]"` => `[1, 2]`
+/// * Integer to Interval(YearMonth), and vice versa: values are a number of months. Int64
+///   shares its buffers with the intervals, Int32 values are converted
+/// * Time32 and Time64 to Utf8: values are formatted as `HH:MM:SS`, with as many
+///   fractional digits as the time unit has, e.g. `12:30:45.500` for milliseconds. Values
+///   outside of a day return null
+/// * Utf8 to Time32 and Time64: strings are parsed as `HH:MM:SS` with optional fractional
+///   seconds, e.g. `23:59:59.999`. Strings that can't be parsed return null
+/// * Timestamp, Date and Time to Int32 or Int64 of the same width, and Int32 to Date32
+///   and Int64 to Date64 or Timestamp: values are shared with the input array, not copied
+/// * Struct to Utf8: each struct is formatted as a JSON object, e.g. `{"a":1,"b":"x"}`
+/// * Struct to Struct: fields are cast by position to the fields of the target struct,
+///   which must have as many fields
+/// * List to Utf8: each list is formatted as a JSON array, e.g. `["a","b"]`
 ///
 /// Unsupported Casts
-/// * To or from `StructArray`
-/// * List to primitive
-/// * Utf8 to boolean
+/// * To `StructArray` from other types, and from `StructArray` to types other than Utf8
+///   and Struct
+/// * List to non-list types other than Utf8
 pub fn cast(array: &ArrayRef, to_type: &DataType) -> Result<ArrayRef> {
+    cast_with_options(array, to_type, &CastOptions::default())
+}
+
+/// Cast array to provided data type, using `options` to control value conversion
+///
+/// In addition to the behavior described on `cast`:
+/// * Utf8 to Utf8: values are rewritten as requested by the options. Invalid UTF-8 is
+///   replaced first, then values are percent-decoded, then Unicode normalized, then ASCII
+///   letters are case converted, then a known prefix or suffix is stripped, then values
+///   are padded
+/// * Float to integer of the same width, and vice versa: with `bitcast`, the IEEE 754
+///   bits are reinterpreted without copying
+/// * Float to Utf8: negative zero is formatted as `0` with `normalize_negative_zero`.
+///   Values are formatted with `float_precision` digits after the decimal point, and as
+///   percentages with `format_percent`
+/// * Integer to Utf8: values are formatted in `int_radix`, e.g. 255 => `ff` in radix 16,
+///   padded with zeros to `int_zero_pad` characters, and digits are grouped by 3 with
+///   `group_digits`
+/// * Numeric to numeric: out of range values become null, saturate or fail the cast, per
+///   `overflow`
+/// * Float to integer: values are rounded per `float_to_int_rounding`
+/// * Float64 to Float32: values that lose precision fail the cast with
+///   `error_on_float_precision_loss`
+/// * Float32 to Float32, Float64 to Float64: NaNs are made canonical with
+///   `canonicalize_nan`
+/// * Timestamp to Timestamp: values are converted to the target unit. Finer units can
+///   overflow, which is handled per `overflow`, and coarser units are rounded per
+///   `float_to_int_rounding`
+/// * Utf8 to numeric: numbers are parsed with `decimal_separator`, after removing unit
+///   suffixes with `strip_nonnumeric_suffix`. Floats can be parsed from percentages with
+///   `parse_percent`. Strings that can't be parsed return `parse_default` or
+///   `parse_default_float` if set
+/// * Int32 to Time32, Int64 to Time64: values outside of a day become null, or fail the
+///   cast with `OverflowMode::Error`
+/// * Date32 to Date32, Date64 to Date64: dates are shifted by `date_epoch_shift_days`
+/// * From List: offsets are checked before casting with `validate_offsets`, even when
+///   casting to the same type
+/// * Integer to Boolean: with `bool_bit`, values are `true` if the given bit is set
+/// * Utf8 to numeric and Boolean: a pair of surrounding double quotes is removed before
+///   parsing with `strip_quotes`
+/// * Utf8 to Boolean: strings are matched against `bool_true_keywords` and
+///   `bool_false_keywords` if set. Unrecognized strings fail the cast with
+///   `OverflowMode::Error`
+pub fn cast_with_options(
+    array: &ArrayRef,
+    to_type: &DataType,
+    options: &CastOptions,
+) -> Result<ArrayRef> {
     use DataType::*;
     let from_type = array.data_type();
 
-    // clone array if types are the same
-    if from_type == to_type {
+    if options.forbid_source_nulls && array.null_count() > 0 {
+        let i = (0..array.len()).find(|i| array.is_null(*i)).unwrap_or(0);
+        return Err(ArrowError::ComputeError(format!(
+            "Cannot cast an array with nulls, found a null at index {}",
+            i
+        )));
+    }
+
+    // checked before the identity cast below, which shares the offsets as they are
+    if options.validate_offsets {
+        if let List(_) = from_type {
+            validate_list_offsets(array)?;
+        }
+    }
+
+    // clone array if types are the same and there are no values to rewrite
+    if from_type == to_type && !options.rewrites_values(from_type) {
         return Ok(array.clone());
     }
     match (from_type, to_type) {
+        (Struct(_), Utf8) => cast_to_json(array),
+        (Struct(ref from_fields), Struct(ref to_fields)) => {
+            if from_fields.len() != to_fields.len() {
+                return Err(ArrowError::ComputeError(format!(
+                    "Cannot cast a struct with {} fields to a struct with {} fields",
+                    from_fields.len(),
+                    to_fields.len()
+                )));
+            }
+            let from = array.as_any().downcast_ref::<StructArray>().unwrap();
+            let columns = to_fields
+                .iter()
+                .enumerate()
+                .map(|(i, field)| {
+                    cast_with_options(from.column(i), field.data_type(), options)
+                        .map(|column| column.data())
+                })
+                .collect::<Result<Vec<_>>>()?;
+            // the columns of a struct array are already sliced to its slots
+            let array_data = ArrayData::new(
+                to_type.clone(),
+                array.len(),
+                Some(array.null_count()),
+                null_bitmap_from_start(from),
+                0,
+                vec![],
+                columns,
+            );
+            Ok(Arc::new(StructArray::from(Arc::new(array_data))) as ArrayRef)
+        }
         (Struct(_), _) => Err(ArrowError::ComputeError(
             "Cannot cast from struct to other types".to_string(),
         )),
@@ -75,28 +434,56 @@ pub fn cast(array: &ArrayRef, to_type: &DataType) -> Result<ArrayRef> {
         )),
         (List(_), List(ref to)) => {
             let data = array.data_ref();
-            let underlying_array = make_array(data.child_data()[0].clone());
-            let cast_array = cast(&underlying_array, &to)?;
+            let list = array.as_any().downcast_ref::<ListArray>().unwrap();
+            // only cast the values in the slots of a sliced list
+            let start = list.value_offset(0);
+            let end = list.value_offset(list.len());
+            let underlying_array =
+                list.values().slice(start as usize, (end - start) as usize);
+            let cast_array = cast_with_options(&underlying_array, &to, options)?;
+            let (value_offsets, null_bit_buffer) = if array.offset() == 0 && start == 0 {
+                // reuse offset buffer
+                (
+                    data.buffers()[0].clone(),
+                    data.null_bitmap().clone().map(|bitmap| bitmap.bits),
+                )
+            } else {
+                let offsets: Vec<i32> = (0..=list.len())
+                    .map(|i| list.value_offset(i) - start)
+                    .collect();
+                (
+                    Buffer::from(offsets[..].to_byte_slice()),
+                    null_bitmap_from_start(list),
+                )
+            };
+            // values that can't be cast become null in the child array, but only
+            // null slots of the source list are null lists
             let array_data = ArrayData::new(
-                *to.clone(),
+                to_type.clone(),
                 array.len(),
-                Some(cast_array.null_count()),
-                cast_array
-                    .data()
-                    .null_bitmap()
-                    .clone()
-                    .map(|bitmap| bitmap.bits),
-                array.offset(),
-                // reuse offset buffer
-                data.buffers().to_vec(),
+                Some(array.null_count()),
+                null_bit_buffer,
+                0,
+                vec![value_offsets],
                 vec![cast_array.data()],
             );
             let list = ListArray::from(Arc::new(array_data));
             Ok(Arc::new(list) as ArrayRef)
         }
+        (List(_), Utf8) => cast_to_json(array),
         (List(_), _) => Err(ArrowError::ComputeError(
             "Cannot cast list to non-list data types".to_string(),
         )),
+        (Utf8, List(_)) if options.parse_json => {
+            let lists = parse_json_lists(array)?;
+            // the source was checked above, nulls in `lists` come from parsing it
+            let options = CastOptions {
+                forbid_source_nulls: false,
+                validate_offsets: false,
+                ..options.clone()
+            };
+            cast_with_options(&lists, to_type, &options)
+        }
         (_, List(ref to)) => {
             // see ARROW-4886 for this limitation
             if array.offset() != 0 {
@@ -106,7 +493,7 @@ pub fn cast(array: &ArrayRef, to_type: &DataType) -> Result<ArrayRef> {
                 ));
             }
             // cast primitive to list's primitive
-            let cast_array = cast(array, &to)?;
+            let cast_array = cast_with_options(array, &to, options)?;
             // create offsets, where if array.len() = 2, we have [0,1,2]
             let offsets: Vec<i32> = (0..array.len() as i32 + 1).collect();
             let value_offsets = Buffer::from(offsets[..].to_byte_slice());
@@ -128,31 +515,28 @@ pub fn cast(array: &ArrayRef, to_type: &DataType) -> Result<ArrayRef> {
             Ok(list_array)
         }
         (_, Boolean) => match from_type {
-            UInt8 => cast_numeric_to_bool::<UInt8Type>(array),
-            UInt16 => cast_numeric_to_bool::<UInt16Type>(array),
-            UInt32 => cast_numeric_to_bool::<UInt32Type>(array),
-            UInt64 => cast_numeric_to_bool::<UInt64Type>(array),
-            Int8 => cast_numeric_to_bool::<Int8Type>(array),
-            Int16 => cast_numeric_to_bool::<Int16Type>(array),
-            Int32 => cast_numeric_to_bool::<Int32Type>(array),
-            Int64 => cast_numeric_to_bool::<Int64Type>(array),
-            Float32 => cast_numeric_to_bool::<Float32Type>(array),
-            Float64 => cast_numeric_to_bool::<Float64Type>(array),
-            Utf8 => Err(ArrowError::ComputeError(format!(
-                "Casting from {:?} to {:?} not supported",
-                from_type, to_type,
-            ))),
-            _ => Err(ArrowError::ComputeError(format!(
-                "Casting from {:?} to {:?} not supported",
-                from_type, to_type,
-            ))),
+            UInt8 => cast_numeric_to_bool::<UInt8Type>(array, options),
+            UInt16 => cast_numeric_to_bool::<UInt16Type>(array, options),
+            UInt32 => cast_numeric_to_bool::<UInt32Type>(array, options),
+            UInt64 => cast_numeric_to_bool::<UInt64Type>(array, options),
+            Int8 => cast_numeric_to_bool::<Int8Type>(array, options),
+            Int16 => cast_numeric_to_bool::<Int16Type>(array, options),
+            Int32 => cast_numeric_to_bool::<Int32Type>(array, options),
+            Int64 => cast_numeric_to_bool::<Int64Type>(array, options),
+            Float32 => cast_numeric_to_bool::<Float32Type>(array, options),
+            Float64 => cast_numeric_to_bool::<Float64Type>(array, options),
+            Utf8 => cast_utf8_to_bool(array, options),
+            _ => Err(ArrowError::CastNotSupported {
+                from: from_type.clone(),
+                to: to_type.clone(),
+            }),
         },
         (Boolean, _) => match to_type {
-            UInt8 => cast_bool_to_numeric::<UInt8Type>(array),
+            UInt8 => cast_bool_to_byte::<UInt8Type>(array),
             UInt16 => cast_bool_to_numeric::<UInt16Type>(array),
             UInt32 => cast_bool_to_numeric::<UInt32Type>(array),
             UInt64 => cast_bool_to_numeric::<UInt64Type>(array),
-            Int8 => cast_bool_to_numeric::<Int8Type>(array),
+            Int8 => cast_bool_to_byte::<Int8Type>(array),
             Int16 => cast_bool_to_numeric::<Int16Type>(array),
             Int32 => cast_bool_to_numeric::<Int32Type>(array),
             Int64 => cast_bool_to_numeric::<Int64Type>(array),
@@ -174,502 +558,4153 @@ pub fn cast(array: &ArrayRef, to_type: &DataType) -> Result<ArrayRef> {
 
                 Ok(Arc::new(b.finish()) as ArrayRef)
             }
-            _ => Err(ArrowError::ComputeError(format!(
-                "Casting from {:?} to {:?} not supported",
-                from_type, to_type,
-            ))),
+            _ => Err(ArrowError::CastNotSupported {
+                from: from_type.clone(),
+                to: to_type.clone(),
+            }),
         },
+        (Utf8, Utf8) => cast_utf8_to_utf8(array, options),
         (Utf8, _) => match to_type {
-            UInt8 => cast_string_to_numeric::<UInt8Type>(array),
-            UInt16 => cast_string_to_numeric::<UInt16Type>(array),
-            UInt32 => cast_string_to_numeric::<UInt32Type>(array),
-            UInt64 => cast_string_to_numeric::<UInt64Type>(array),
-            Int8 => cast_string_to_numeric::<Int8Type>(array),
-            Int16 => cast_string_to_numeric::<Int16Type>(array),
-            Int32 => cast_string_to_numeric::<Int32Type>(array),
-            Int64 => cast_string_to_numeric::<Int64Type>(array),
-            Float32 => cast_string_to_numeric::<Float32Type>(array),
-            Float64 => cast_string_to_numeric::<Float64Type>(array),
-            _ => Err(ArrowError::ComputeError(format!(
-                "Casting from {:?} to {:?} not supported",
-                from_type, to_type,
-            ))),
+            UInt8 => cast_string_to_numeric::<UInt8Type>(array, options),
+            UInt16 => cast_string_to_numeric::<UInt16Type>(array, options),
+            UInt32 => cast_string_to_numeric::<UInt32Type>(array, options),
+            UInt64 => cast_string_to_numeric::<UInt64Type>(array, options),
+            Int8 => cast_string_to_numeric::<Int8Type>(array, options),
+            Int16 => cast_string_to_numeric::<Int16Type>(array, options),
+            Int32 => cast_string_to_numeric::<Int32Type>(array, options),
+            Int64 => cast_string_to_numeric::<Int64Type>(array, options),
+            Float32 => cast_string_to_numeric::<Float32Type>(array, options),
+            Float64 => cast_string_to_numeric::<Float64Type>(array, options),
+            Time32(TimeUnit::Second)
+            | Time32(TimeUnit::Millisecond)
+            | Time64(TimeUnit::Microsecond)
+            | Time64(TimeUnit::Nanosecond) => {
+                cast_string_to_time(array, to_type, options)
+            }
+            _ => Err(ArrowError::CastNotSupported {
+                from: from_type.clone(),
+                to: to_type.clone(),
+            }),
         },
         (_, Utf8) => match from_type {
-            UInt8 => cast_numeric_to_string::<UInt8Type>(array),
-            UInt16 => cast_numeric_to_string::<UInt16Type>(array),
-            UInt32 => cast_numeric_to_string::<UInt32Type>(array),
-            UInt64 => cast_numeric_to_string::<UInt64Type>(array),
-            Int8 => cast_numeric_to_string::<Int8Type>(array),
-            Int16 => cast_numeric_to_string::<Int16Type>(array),
-            Int32 => cast_numeric_to_string::<Int32Type>(array),
-            Int64 => cast_numeric_to_string::<Int64Type>(array),
-            Float32 => cast_numeric_to_string::<Float32Type>(array),
-            Float64 => cast_numeric_to_string::<Float64Type>(array),
-            _ => Err(ArrowError::ComputeError(format!(
-                "Casting from {:?} to {:?} not supported",
-                from_type, to_type,
-            ))),
+            UInt8 => cast_int_to_string::<UInt8Type>(array, options),
+            UInt16 => cast_int_to_string::<UInt16Type>(array, options),
+            UInt32 => cast_int_to_string::<UInt32Type>(array, options),
+            UInt64 => cast_int_to_string::<UInt64Type>(array, options),
+            Int8 => cast_int_to_string::<Int8Type>(array, options),
+            Int16 => cast_int_to_string::<Int16Type>(array, options),
+            Int32 => cast_int_to_string::<Int32Type>(array, options),
+            Int64 => cast_int_to_string::<Int64Type>(array, options),
+            Float32 => cast_numeric_to_string::<Float32Type>(array, options),
+            Float64 => cast_numeric_to_string::<Float64Type>(array, options),
+            Time32(ref unit) => {
+                cast_time_to_string::<Int32Type>(&reinterpret_array(array, &Int32), unit)
+            }
+            Time64(ref unit) => {
+                cast_time_to_string::<Int64Type>(&reinterpret_array(array, &Int64), unit)
+            }
+            _ => Err(ArrowError::CastNotSupported {
+                from: from_type.clone(),
+                to: to_type.clone(),
+            }),
+        },
+
+        (Float32, Int32)
+        | (Float32, UInt32)
+        | (Int32, Float32)
+        | (UInt32, Float32)
+        | (Float64, Int64)
+        | (Float64, UInt64)
+        | (Int64, Float64)
+        | (UInt64, Float64)
+            if options.bitcast =>
+        {
+            Ok(reinterpret_array(array, to_type))
+        }
+
+        (Timestamp(ref from_unit), Timestamp(ref to_unit)) => {
+            cast_timestamp_unit(array, from_unit, to_unit, options)
+        }
+        (Int32, Time32(TimeUnit::Second))
+        | (Int32, Time32(TimeUnit::Millisecond))
+        | (Int64, Time64(TimeUnit::Microsecond))
+        | (Int64, Time64(TimeUnit::Nanosecond)) => match from_type {
+            Int32 => cast_int_to_time::<Int32Type>(array, to_type, options),
+            _ => cast_int_to_time::<Int64Type>(array, to_type, options),
         },
+        // year-month intervals count months in an i64
+        (Int64, Interval(IntervalUnit::YearMonth))
+        | (Interval(IntervalUnit::YearMonth), Int64) => {
+            Ok(reinterpret_array(array, to_type))
+        }
+        (Int32, Interval(IntervalUnit::YearMonth)) => {
+            let months = cast_with_options(array, &Int64, options)?;
+            Ok(reinterpret_array(&months, to_type))
+        }
+        (Interval(IntervalUnit::YearMonth), Int32) => {
+            let months = reinterpret_array(array, &Int64);
+            cast_with_options(&months, to_type, options)
+        }
+        // temporal types are stored as integers of the same width
+        (Timestamp(_), Int64)
+        | (Date64(_), Int64)
+        | (Date32(_), Int32)
+        | (Time64(_), Int64)
+        | (Time32(_), Int32)
+        | (Int64, Timestamp(_))
+        | (Int64, Date64(_))
+        | (Int32, Date32(_)) => Ok(reinterpret_array(array, to_type)),
+        (Date32(_), Date32(_)) => {
+            shift_dates::<Date32Type>(array, i64::from(options.date_epoch_shift_days))
+        }
+        (Date64(_), Date64(_)) => shift_dates::<Date64Type>(
+            array,
+            i64::from(options.date_epoch_shift_days) * MILLISECONDS_IN_DAY,
+        ),
+
+        (Float32, Float32) => canonicalize_nans::<Float32Type>(array),
+        (Float64, Float64) => canonicalize_nans::<Float64Type>(array),
 
         // start numeric casts
-        (UInt8, UInt16) => cast_numeric_arrays::<UInt8Type, UInt16Type>(array),
-        (UInt8, UInt32) => cast_numeric_arrays::<UInt8Type, UInt32Type>(array),
-        (UInt8, UInt64) => cast_numeric_arrays::<UInt8Type, UInt64Type>(array),
-        (UInt8, Int8) => cast_numeric_arrays::<UInt8Type, Int8Type>(array),
-        (UInt8, Int16) => cast_numeric_arrays::<UInt8Type, Int16Type>(array),
-        (UInt8, Int32) => cast_numeric_arrays::<UInt8Type, Int32Type>(array),
-        (UInt8, Int64) => cast_numeric_arrays::<UInt8Type, Int64Type>(array),
-        (UInt8, Float32) => cast_numeric_arrays::<UInt8Type, Float32Type>(array),
-        (UInt8, Float64) => cast_numeric_arrays::<UInt8Type, Float64Type>(array),
-
-        (UInt16, UInt8) => cast_numeric_arrays::<UInt16Type, UInt8Type>(array),
-        (UInt16, UInt32) => cast_numeric_arrays::<UInt16Type, UInt32Type>(array),
-        (UInt16, UInt64) => cast_numeric_arrays::<UInt16Type, UInt64Type>(array),
-        (UInt16, Int8) => cast_numeric_arrays::<UInt16Type, Int8Type>(array),
-        (UInt16, Int16) => cast_numeric_arrays::<UInt16Type, Int16Type>(array),
-        (UInt16, Int32) => cast_numeric_arrays::<UInt16Type, Int32Type>(array),
-        (UInt16, Int64) => cast_numeric_arrays::<UInt16Type, Int64Type>(array),
-        (UInt16, Float32) => cast_numeric_arrays::<UInt16Type, Float32Type>(array),
-        (UInt16, Float64) => cast_numeric_arrays::<UInt16Type, Float64Type>(array),
-
-        (UInt32, UInt8) => cast_numeric_arrays::<UInt32Type, UInt8Type>(array),
-        (UInt32, UInt16) => cast_numeric_arrays::<UInt32Type, UInt16Type>(array),
-        (UInt32, UInt64) => cast_numeric_arrays::<UInt32Type, UInt64Type>(array),
-        (UInt32, Int8) => cast_numeric_arrays::<UInt32Type, Int8Type>(array),
-        (UInt32, Int16) => cast_numeric_arrays::<UInt32Type, Int16Type>(array),
-        (UInt32, Int32) => cast_numeric_arrays::<UInt32Type, Int32Type>(array),
-        (UInt32, Int64) => cast_numeric_arrays::<UInt32Type, Int64Type>(array),
-        (UInt32, Float32) => cast_numeric_arrays::<UInt32Type, Float32Type>(array),
-        (UInt32, Float64) => cast_numeric_arrays::<UInt32Type, Float64Type>(array),
-
-        (UInt64, UInt8) => cast_numeric_arrays::<UInt64Type, UInt8Type>(array),
-        (UInt64, UInt16) => cast_numeric_arrays::<UInt64Type, UInt16Type>(array),
-        (UInt64, UInt32) => cast_numeric_arrays::<UInt64Type, UInt32Type>(array),
-        (UInt64, Int8) => cast_numeric_arrays::<UInt64Type, Int8Type>(array),
-        (UInt64, Int16) => cast_numeric_arrays::<UInt64Type, Int16Type>(array),
-        (UInt64, Int32) => cast_numeric_arrays::<UInt64Type, Int32Type>(array),
-        (UInt64, Int64) => cast_numeric_arrays::<UInt64Type, Int64Type>(array),
-        (UInt64, Float32) => cast_numeric_arrays::<UInt64Type, Float32Type>(array),
-        (UInt64, Float64) => cast_numeric_arrays::<UInt64Type, Float64Type>(array),
-
-        (Int8, UInt8) => cast_numeric_arrays::<Int8Type, UInt8Type>(array),
-        (Int8, UInt16) => cast_numeric_arrays::<Int8Type, UInt16Type>(array),
-        (Int8, UInt32) => cast_numeric_arrays::<Int8Type, UInt32Type>(array),
-        (Int8, UInt64) => cast_numeric_arrays::<Int8Type, UInt64Type>(array),
-        (Int8, Int16) => cast_numeric_arrays::<Int8Type, Int16Type>(array),
-        (Int8, Int32) => cast_numeric_arrays::<Int8Type, Int32Type>(array),
-        (Int8, Int64) => cast_numeric_arrays::<Int8Type, Int64Type>(array),
-        (Int8, Float32) => cast_numeric_arrays::<Int8Type, Float32Type>(array),
-        (Int8, Float64) => cast_numeric_arrays::<Int8Type, Float64Type>(array),
-
-        (Int16, UInt8) => cast_numeric_arrays::<Int16Type, UInt8Type>(array),
-        (Int16, UInt16) => cast_numeric_arrays::<Int16Type, UInt16Type>(array),
-        (Int16, UInt32) => cast_numeric_arrays::<Int16Type, UInt32Type>(array),
-        (Int16, UInt64) => cast_numeric_arrays::<Int16Type, UInt64Type>(array),
-        (Int16, Int8) => cast_numeric_arrays::<Int16Type, Int8Type>(array),
-        (Int16, Int32) => cast_numeric_arrays::<Int16Type, Int32Type>(array),
-        (Int16, Int64) => cast_numeric_arrays::<Int16Type, Int64Type>(array),
-        (Int16, Float32) => cast_numeric_arrays::<Int16Type, Float32Type>(array),
-        (Int16, Float64) => cast_numeric_arrays::<Int16Type, Float64Type>(array),
-
-        (Int32, UInt8) => cast_numeric_arrays::<Int32Type, UInt8Type>(array),
-        (Int32, UInt16) => cast_numeric_arrays::<Int32Type, UInt16Type>(array),
-        (Int32, UInt32) => cast_numeric_arrays::<Int32Type, UInt32Type>(array),
-        (Int32, UInt64) => cast_numeric_arrays::<Int32Type, UInt64Type>(array),
-        (Int32, Int8) => cast_numeric_arrays::<Int32Type, Int8Type>(array),
-        (Int32, Int16) => cast_numeric_arrays::<Int32Type, Int16Type>(array),
-        (Int32, Int64) => cast_numeric_arrays::<Int32Type, Int64Type>(array),
-        (Int32, Float32) => cast_numeric_arrays::<Int32Type, Float32Type>(array),
-        (Int32, Float64) => cast_numeric_arrays::<Int32Type, Float64Type>(array),
-
-        (Int64, UInt8) => cast_numeric_arrays::<Int64Type, UInt8Type>(array),
-        (Int64, UInt16) => cast_numeric_arrays::<Int64Type, UInt16Type>(array),
-        (Int64, UInt32) => cast_numeric_arrays::<Int64Type, UInt32Type>(array),
-        (Int64, UInt64) => cast_numeric_arrays::<Int64Type, UInt64Type>(array),
-        (Int64, Int8) => cast_numeric_arrays::<Int64Type, Int8Type>(array),
-        (Int64, Int16) => cast_numeric_arrays::<Int64Type, Int16Type>(array),
-        (Int64, Int32) => cast_numeric_arrays::<Int64Type, Int32Type>(array),
-        (Int64, Float32) => cast_numeric_arrays::<Int64Type, Float32Type>(array),
-        (Int64, Float64) => cast_numeric_arrays::<Int64Type, Float64Type>(array),
-
-        (Float32, UInt8) => cast_numeric_arrays::<Float32Type, UInt8Type>(array),
-        (Float32, UInt16) => cast_numeric_arrays::<Float32Type, UInt16Type>(array),
-        (Float32, UInt32) => cast_numeric_arrays::<Float32Type, UInt32Type>(array),
-        (Float32, UInt64) => cast_numeric_arrays::<Float32Type, UInt64Type>(array),
-        (Float32, Int8) => cast_numeric_arrays::<Float32Type, Int8Type>(array),
-        (Float32, Int16) => cast_numeric_arrays::<Float32Type, Int16Type>(array),
-        (Float32, Int32) => cast_numeric_arrays::<Float32Type, Int32Type>(array),
-        (Float32, Int64) => cast_numeric_arrays::<Float32Type, Int64Type>(array),
-        (Float32, Float64) => cast_numeric_arrays::<Float32Type, Float64Type>(array),
-
-        (Float64, UInt8) => cast_numeric_arrays::<Float64Type, UInt8Type>(array),
-        (Float64, UInt16) => cast_numeric_arrays::<Float64Type, UInt16Type>(array),
-        (Float64, UInt32) => cast_numeric_arrays::<Float64Type, UInt32Type>(array),
-        (Float64, UInt64) => cast_numeric_arrays::<Float64Type, UInt64Type>(array),
-        (Float64, Int8) => cast_numeric_arrays::<Float64Type, Int8Type>(array),
-        (Float64, Int16) => cast_numeric_arrays::<Float64Type, Int16Type>(array),
-        (Float64, Int32) => cast_numeric_arrays::<Float64Type, Int32Type>(array),
-        (Float64, Int64) => cast_numeric_arrays::<Float64Type, Int64Type>(array),
-        (Float64, Float32) => cast_numeric_arrays::<Float64Type, Float32Type>(array),
+        (UInt8, UInt16) => cast_numeric_arrays::<UInt8Type, UInt16Type>(array, options),
+        (UInt8, UInt32) => cast_numeric_arrays::<UInt8Type, UInt32Type>(array, options),
+        (UInt8, UInt64) => cast_numeric_arrays::<UInt8Type, UInt64Type>(array, options),
+        (UInt8, Int8) => cast_numeric_arrays::<UInt8Type, Int8Type>(array, options),
+        (UInt8, Int16) => cast_numeric_arrays::<UInt8Type, Int16Type>(array, options),
+        (UInt8, Int32) => cast_numeric_arrays::<UInt8Type, Int32Type>(array, options),
+        (UInt8, Int64) => cast_numeric_arrays::<UInt8Type, Int64Type>(array, options),
+        (UInt8, Float32) => cast_numeric_arrays::<UInt8Type, Float32Type>(array, options),
+        (UInt8, Float64) => cast_numeric_arrays::<UInt8Type, Float64Type>(array, options),
+
+        (UInt16, UInt8) => cast_numeric_arrays::<UInt16Type, UInt8Type>(array, options),
+        (UInt16, UInt32) => cast_numeric_arrays::<UInt16Type, UInt32Type>(array, options),
+        (UInt16, UInt64) => cast_numeric_arrays::<UInt16Type, UInt64Type>(array, options),
+        (UInt16, Int8) => cast_numeric_arrays::<UInt16Type, Int8Type>(array, options),
+        (UInt16, Int16) => cast_numeric_arrays::<UInt16Type, Int16Type>(array, options),
+        (UInt16, Int32) => cast_numeric_arrays::<UInt16Type, Int32Type>(array, options),
+        (UInt16, Int64) => cast_numeric_arrays::<UInt16Type, Int64Type>(array, options),
+        (UInt16, Float32) => {
+            cast_numeric_arrays::<UInt16Type, Float32Type>(array, options)
+        }
+        (UInt16, Float64) => {
+            cast_numeric_arrays::<UInt16Type, Float64Type>(array, options)
+        }
+
+        (UInt32, UInt8) => cast_numeric_arrays::<UInt32Type, UInt8Type>(array, options),
+        (UInt32, UInt16) => cast_numeric_arrays::<UInt32Type, UInt16Type>(array, options),
+        (UInt32, UInt64) => cast_numeric_arrays::<UInt32Type, UInt64Type>(array, options),
+        (UInt32, Int8) => cast_numeric_arrays::<UInt32Type, Int8Type>(array, options),
+        (UInt32, Int16) => cast_numeric_arrays::<UInt32Type, Int16Type>(array, options),
+        (UInt32, Int32) => cast_numeric_arrays::<UInt32Type, Int32Type>(array, options),
+        (UInt32, Int64) => cast_numeric_arrays::<UInt32Type, Int64Type>(array, options),
+        (UInt32, Float32) => {
+            cast_numeric_arrays::<UInt32Type, Float32Type>(array, options)
+        }
+        (UInt32, Float64) => {
+            cast_numeric_arrays::<UInt32Type, Float64Type>(array, options)
+        }
+
+        (UInt64, UInt8) => cast_numeric_arrays::<UInt64Type, UInt8Type>(array, options),
+        (UInt64, UInt16) => cast_numeric_arrays::<UInt64Type, UInt16Type>(array, options),
+        (UInt64, UInt32) => cast_numeric_arrays::<UInt64Type, UInt32Type>(array, options),
+        (UInt64, Int8) => cast_numeric_arrays::<UInt64Type, Int8Type>(array, options),
+        (UInt64, Int16) => cast_numeric_arrays::<UInt64Type, Int16Type>(array, options),
+        (UInt64, Int32) => cast_numeric_arrays::<UInt64Type, Int32Type>(array, options),
+        (UInt64, Int64) => cast_numeric_arrays::<UInt64Type, Int64Type>(array, options),
+        (UInt64, Float32) => {
+            cast_numeric_arrays::<UInt64Type, Float32Type>(array, options)
+        }
+        (UInt64, Float64) => {
+            cast_numeric_arrays::<UInt64Type, Float64Type>(array, options)
+        }
+
+        (Int8, UInt8) => cast_numeric_arrays::<Int8Type, UInt8Type>(array, options),
+        (Int8, UInt16) => cast_numeric_arrays::<Int8Type, UInt16Type>(array, options),
+        (Int8, UInt32) => cast_numeric_arrays::<Int8Type, UInt32Type>(array, options),
+        (Int8, UInt64) => cast_numeric_arrays::<Int8Type, UInt64Type>(array, options),
+        (Int8, Int16) => cast_numeric_arrays::<Int8Type, Int16Type>(array, options),
+        (Int8, Int32) => cast_numeric_arrays::<Int8Type, Int32Type>(array, options),
+        (Int8, Int64) => cast_numeric_arrays::<Int8Type, Int64Type>(array, options),
+        (Int8, Float32) => cast_numeric_arrays::<Int8Type, Float32Type>(array, options),
+        (Int8, Float64) => cast_numeric_arrays::<Int8Type, Float64Type>(array, options),
+
+        (Int16, UInt8) => cast_numeric_arrays::<Int16Type, UInt8Type>(array, options),
+        (Int16, UInt16) => cast_numeric_arrays::<Int16Type, UInt16Type>(array, options),
+        (Int16, UInt32) => cast_numeric_arrays::<Int16Type, UInt32Type>(array, options),
+        (Int16, UInt64) => cast_numeric_arrays::<Int16Type, UInt64Type>(array, options),
+        (Int16, Int8) => cast_numeric_arrays::<Int16Type, Int8Type>(array, options),
+        (Int16, Int32) => cast_numeric_arrays::<Int16Type, Int32Type>(array, options),
+        (Int16, Int64) => cast_numeric_arrays::<Int16Type, Int64Type>(array, options),
+        (Int16, Float32) => cast_numeric_arrays::<Int16Type, Float32Type>(array, options),
+        (Int16, Float64) => cast_numeric_arrays::<Int16Type, Float64Type>(array, options),
+
+        (Int32, UInt8) => cast_numeric_arrays::<Int32Type, UInt8Type>(array, options),
+        (Int32, UInt16) => cast_numeric_arrays::<Int32Type, UInt16Type>(array, options),
+        (Int32, UInt32) => cast_numeric_arrays::<Int32Type, UInt32Type>(array, options),
+        (Int32, UInt64) => cast_numeric_arrays::<Int32Type, UInt64Type>(array, options),
+        (Int32, Int8) => cast_numeric_arrays::<Int32Type, Int8Type>(array, options),
+        (Int32, Int16) => cast_numeric_arrays::<Int32Type, Int16Type>(array, options),
+        (Int32, Int64) => cast_numeric_arrays::<Int32Type, Int64Type>(array, options),
+        (Int32, Float32) => cast_numeric_arrays::<Int32Type, Float32Type>(array, options),
+        (Int32, Float64) => cast_numeric_arrays::<Int32Type, Float64Type>(array, options),
+
+        (Int64, UInt8) => cast_numeric_arrays::<Int64Type, UInt8Type>(array, options),
+        (Int64, UInt16) => cast_numeric_arrays::<Int64Type, UInt16Type>(array, options),
+        (Int64, UInt32) => cast_numeric_arrays::<Int64Type, UInt32Type>(array, options),
+        (Int64, UInt64) => cast_numeric_arrays::<Int64Type, UInt64Type>(array, options),
+        (Int64, Int8) => cast_numeric_arrays::<Int64Type, Int8Type>(array, options),
+        (Int64, Int16) => cast_numeric_arrays::<Int64Type, Int16Type>(array, options),
+        (Int64, Int32) => cast_numeric_arrays::<Int64Type, Int32Type>(array, options),
+        (Int64, Float32) => cast_numeric_arrays::<Int64Type, Float32Type>(array, options),
+        (Int64, Float64) => cast_numeric_arrays::<Int64Type, Float64Type>(array, options),
+
+        (Float32, UInt8) => cast_numeric_arrays::<Float32Type, UInt8Type>(array, options),
+        (Float32, UInt16) => {
+            cast_numeric_arrays::<Float32Type, UInt16Type>(array, options)
+        }
+        (Float32, UInt32) => {
+            cast_numeric_arrays::<Float32Type, UInt32Type>(array, options)
+        }
+        (Float32, UInt64) => {
+            cast_numeric_arrays::<Float32Type, UInt64Type>(array, options)
+        }
+        (Float32, Int8) => cast_numeric_arrays::<Float32Type, Int8Type>(array, options),
+        (Float32, Int16) => cast_numeric_arrays::<Float32Type, Int16Type>(array, options),
+        (Float32, Int32) => cast_numeric_arrays::<Float32Type, Int32Type>(array, options),
+        (Float32, Int64) => cast_numeric_arrays::<Float32Type, Int64Type>(array, options),
+        (Float32, Float64) => {
+            cast_numeric_arrays::<Float32Type, Float64Type>(array, options)
+        }
+
+        (Float64, UInt8) => cast_numeric_arrays::<Float64Type, UInt8Type>(array, options),
+        (Float64, UInt16) => {
+            cast_numeric_arrays::<Float64Type, UInt16Type>(array, options)
+        }
+        (Float64, UInt32) => {
+            cast_numeric_arrays::<Float64Type, UInt32Type>(array, options)
+        }
+        (Float64, UInt64) => {
+            cast_numeric_arrays::<Float64Type, UInt64Type>(array, options)
+        }
+        (Float64, Int8) => cast_numeric_arrays::<Float64Type, Int8Type>(array, options),
+        (Float64, Int16) => cast_numeric_arrays::<Float64Type, Int16Type>(array, options),
+        (Float64, Int32) => cast_numeric_arrays::<Float64Type, Int32Type>(array, options),
+        (Float64, Int64) => cast_numeric_arrays::<Float64Type, Int64Type>(array, options),
+        (Float64, Float32) => {
+            if options.error_on_float_precision_loss {
+                check_f32_precision(array)?;
+            }
+            cast_numeric_arrays::<Float64Type, Float32Type>(array, options)
+        }
         // end numeric casts
-        (_, _) => Err(ArrowError::ComputeError(format!(
-            "Casting from {:?} to {:?} not supported",
-            from_type, to_type,
-        ))),
+        (_, _) => Err(ArrowError::CastNotSupported {
+            from: from_type.clone(),
+            to: to_type.clone(),
+        }),
+    }
+}
+
+/// Cast each column of a record batch to the type of the matching field of `schema`
+pub fn cast_batch(batch: &RecordBatch, schema: &Arc<Schema>) -> Result<RecordBatch> {
+    cast_batch_with_options(batch, schema, &CastOptions::default())
+}
+
+/// Cast each column of a record batch to the type of the matching field of `schema`,
+/// using `options` to control value conversion
+pub fn cast_batch_with_options(
+    batch: &RecordBatch,
+    schema: &Arc<Schema>,
+    options: &CastOptions,
+) -> Result<RecordBatch> {
+    if batch.num_columns() != schema.fields().len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot cast a record batch with {} columns to a schema with {} fields",
+            batch.num_columns(),
+            schema.fields().len()
+        )));
+    }
+    let columns = schema
+        .fields()
+        .iter()
+        .enumerate()
+        .map(|(i, field)| cast_with_options(batch.column(i), field.data_type(), options))
+        .collect::<Result<Vec<_>>>()?;
+    RecordBatch::try_new(schema.clone(), columns)
+}
+
+/// Cast each of `batches` to `schema` with `cast_batch`. The cast batches share a
+/// single schema.
+pub fn cast_batches(
+    batches: &[RecordBatch],
+    schema: &Arc<Schema>,
+) -> Result<Vec<RecordBatch>> {
+    cast_batches_with_options(batches, schema, &CastOptions::default())
+}
+
+/// Cast each of `batches` to `schema` with `cast_batch_with_options`. The cast batches
+/// share a single schema.
+pub fn cast_batches_with_options(
+    batches: &[RecordBatch],
+    schema: &Arc<Schema>,
+    options: &CastOptions,
+) -> Result<Vec<RecordBatch>> {
+    batches
+        .iter()
+        .map(|batch| cast_batch_with_options(batch, schema, options))
+        .collect()
+}
+
+/// Checks that the offsets of a list array are non-decreasing and point into its values
+fn validate_list_offsets(array: &ArrayRef) -> Result<()> {
+    let data = array.data_ref();
+    let required_len = (array.offset() + array.len() + 1) * std::mem::size_of::<i32>();
+    if data.buffers()[0].len() < required_len {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "List offset buffer has {} bytes, expected at least {}",
+            data.buffers()[0].len(),
+            required_len
+        )));
+    }
+    let list = array.as_any().downcast_ref::<ListArray>().unwrap();
+    let values_len = list.values().len() as i32;
+    let mut previous = 0;
+    for i in 0..=list.len() {
+        let offset = list.value_offset(i);
+        if offset < previous || offset > values_len {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Invalid list offset {} at index {}, values have length {}",
+                offset, i, values_len
+            )));
+        }
+        previous = offset;
+    }
+    Ok(())
+}
+
+/// Number of milliseconds in a day, the unit of `Date64`
+const MILLISECONDS_IN_DAY: i64 = 86_400_000;
+
+/// Cast array to provided data type, also returning why each new null was produced
+///
+/// The second array is parallel to the cast output. Slots that the cast turned from a
+/// value into a null hold one of:
+/// * `"parse"`: a string could not be parsed into the target type
+/// * `"non-finite"`: a `NaN` or infinite float has no representation in the target
+/// * `"overflow"`: a value does not fit into the target type
+///
+/// All other slots are null. If the cast did not produce any new nulls, the reason
+/// array is empty rather than all null.
+pub fn cast_with_reasons(
+    array: &ArrayRef,
+    to_type: &DataType,
+) -> Result<(ArrayRef, BinaryArray)> {
+    let cast_array = cast(array, to_type)?;
+    if cast_array.null_count() == array.null_count() {
+        return Ok((cast_array, BinaryBuilder::new(0).finish()));
+    }
+
+    let mut b = BinaryBuilder::new(array.len());
+    for i in 0..array.len() {
+        if array.is_valid(i) && cast_array.is_null(i) {
+            b.append_string(null_reason(array, i))?;
+        } else {
+            b.append(false)?;
+        }
+    }
+
+    Ok((cast_array, b.finish()))
+}
+
+/// Cast Utf8 to numeric types, also returning the number of strings that could not be
+/// parsed
+///
+/// This counts the `"parse"` reasons of `cast_with_reasons` without building them.
+pub fn cast_string_to_numeric_count_failures(
+    array: &ArrayRef,
+    to_type: &DataType,
+) -> Result<(ArrayRef, usize)> {
+    if array.data_type() != &DataType::Utf8 {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Expected a Utf8 array, found {:?}",
+            array.data_type()
+        )));
+    }
+    match to_type {
+        DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::UInt64
+        | DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::Float32
+        | DataType::Float64 => {}
+        _ => {
+            return Err(ArrowError::CastNotSupported {
+                from: array.data_type().clone(),
+                to: to_type.clone(),
+            })
+        }
+    }
+    let cast_array = cast(array, to_type)?;
+    // every null that the cast added is a string that could not be parsed
+    let failures = cast_array.null_count() - array.null_count();
+    Ok((cast_array, failures))
+}
+
+/// Cast array to provided data type, also returning whether the output shares its
+/// buffers with `array`
+///
+/// Identity casts and zero-copy reinterpretations share all of their buffers with the
+/// input, so callers can skip copies they would otherwise make to own the data. Casts
+/// that only reuse some buffers, such as the offsets of a list cast, are not shared.
+pub fn cast_maybe_shared(
+    array: &ArrayRef,
+    to_type: &DataType,
+) -> Result<(ArrayRef, bool)> {
+    let cast_array = cast(array, to_type)?;
+    let shared = Arc::ptr_eq(array, &cast_array) || shares_buffers(array, &cast_array);
+    Ok((cast_array, shared))
+}
+
+/// Cast array to provided data type, then null out the slots that are not set in
+/// `validity`
+///
+/// `validity` is a bitmap with a bit per slot of `array`, starting at bit 0. Slots that
+/// are null in the cast array stay null.
+pub fn cast_with_validity(
+    array: &ArrayRef,
+    to_type: &DataType,
+    validity: &Buffer,
+) -> Result<ArrayRef> {
+    let required_len = bit_util::ceil(array.len(), 8);
+    if validity.len() < required_len {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Validity buffer has {} bytes, expected at least {}",
+            validity.len(),
+            required_len
+        )));
+    }
+    let cast_array = cast(array, to_type)?;
+    let data = cast_array.data_ref();
+    let offset = cast_array.offset();
+
+    // the null bitmap of the cast array starts at its offset
+    let num_bytes = bit_util::ceil(offset + cast_array.len(), 8);
+    let mut nulls = MutableBuffer::new(num_bytes).with_bitset(num_bytes, false);
+    let mut null_count = 0;
+    for i in 0..cast_array.len() {
+        if cast_array.is_valid(i) && bit_util::get_bit(validity.data(), i) {
+            bit_util::set_bit(nulls.data_mut(), offset + i);
+        } else {
+            null_count += 1;
+        }
+    }
+
+    let array_data = ArrayData::new(
+        to_type.clone(),
+        cast_array.len(),
+        Some(null_count),
+        Some(nulls.freeze()),
+        offset,
+        data.buffers().to_vec(),
+        data.child_data().to_vec(),
+    );
+    Ok(make_array(Arc::new(array_data)))
+}
+
+/// Whether all buffers of `to` are buffers of `from`
+fn shares_buffers(from: &ArrayRef, to: &ArrayRef) -> bool {
+    let from_buffers = from.data_ref().buffers();
+    let to_data = to.data_ref();
+    to_data.child_data().is_empty()
+        && !to_data.buffers().is_empty()
+        && to_data.buffers().iter().all(|to_buffer| {
+            from_buffers
+                .iter()
+                .any(|from_buffer| from_buffer.raw_data() == to_buffer.raw_data())
+        })
+}
+
+/// The result of `cast_view`, which defers zero-copy reinterpretations
+#[derive(Clone)]
+pub enum CastView {
+    /// An array that was cast eagerly
+    Owned(ArrayRef),
+    /// The values of `base`, read as `as_type`
+    Reinterpret { base: ArrayRef, as_type: DataType },
+}
+
+impl CastView {
+    /// Returns the data type of the cast array
+    pub fn data_type(&self) -> &DataType {
+        match self {
+            CastView::Owned(array) => array.data_type(),
+            CastView::Reinterpret { as_type, .. } => as_type,
+        }
+    }
+
+    /// Returns the cast array, building it from the base array of a reinterpretation.
+    /// Its buffers are shared with the base array.
+    pub fn materialize(&self) -> ArrayRef {
+        match self {
+            CastView::Owned(array) => array.clone(),
+            CastView::Reinterpret { base, as_type } => reinterpret_array(base, as_type),
+        }
+    }
+}
+
+/// Cast array to provided data type, deferring casts that reinterpret integers as
+/// temporal types of the same width, and vice versa
+///
+/// The reinterpreted casts are `Int32` to and from `Date32`, and `Int64` to and from
+/// `Date64` and `Timestamp`. All other casts are done by `cast`.
+pub fn cast_view(array: &ArrayRef, to_type: &DataType) -> Result<CastView> {
+    use DataType::*;
+    match (array.data_type(), to_type) {
+        (Int32, Date32(_))
+        | (Date32(_), Int32)
+        | (Int64, Date64(_))
+        | (Date64(_), Int64)
+        | (Int64, Timestamp(_))
+        | (Timestamp(_), Int64) => Ok(CastView::Reinterpret {
+            base: array.clone(),
+            as_type: to_type.clone(),
+        }),
+        _ => Ok(CastView::Owned(cast(array, to_type)?)),
+    }
+}
+
+/// Classifies why the valid slot `i` of `array` could not be cast
+fn null_reason(array: &ArrayRef, i: usize) -> &'static str {
+    match array.data_type() {
+        DataType::Utf8 => "parse",
+        DataType::Float32 => {
+            let from = array.as_any().downcast_ref::<Float32Array>().unwrap();
+            if from.value(i).is_finite() {
+                "overflow"
+            } else {
+                "non-finite"
+            }
+        }
+        DataType::Float64 => {
+            let from = array.as_any().downcast_ref::<Float64Array>().unwrap();
+            if from.value(i).is_finite() {
+                "overflow"
+            } else {
+                "non-finite"
+            }
+        }
+        _ => "overflow",
     }
 }
 
+/// Reinterpret the buffers of an array as `to_type` without copying them
+///
+/// The physical layout of the array's type and `to_type` must be identical.
+fn reinterpret_array(array: &ArrayRef, to_type: &DataType) -> ArrayRef {
+    let data = array.data_ref();
+    let array_data = ArrayData::new(
+        to_type.clone(),
+        array.len(),
+        Some(array.null_count()),
+        data.null_bitmap().clone().map(|bitmap| bitmap.bits),
+        array.offset(),
+        data.buffers().to_vec(),
+        vec![],
+    );
+    make_array(Arc::new(array_data))
+}
+
 /// Convert Array into a PrimitiveArray of type, and apply numeric cast
-fn cast_numeric_arrays<FROM, TO>(from: &ArrayRef) -> Result<ArrayRef>
+fn cast_numeric_arrays<FROM, TO>(
+    from: &ArrayRef,
+    options: &CastOptions,
+) -> Result<ArrayRef>
 where
     FROM: ArrowNumericType,
     TO: ArrowNumericType,
-    FROM::Native: num::NumCast,
-    TO::Native: num::NumCast,
+    FROM::Native: num::NumCast + num::Bounded,
+    TO::Native: num::NumCast + num::Bounded,
 {
-    match numeric_cast::<FROM, TO>(
-        from.as_any()
-            .downcast_ref::<PrimitiveArray<FROM>>()
-            .unwrap(),
-    ) {
+    if from.null_count() == from.len() {
+        return Ok(null_primitive_array::<TO>(from.len()));
+    }
+    let from = from
+        .as_any()
+        .downcast_ref::<PrimitiveArray<FROM>>()
+        .unwrap();
+    let rounded;
+    let from = if is_float(&FROM::get_data_type())
+        && !is_float(&TO::get_data_type())
+        && options.float_to_int_rounding != FloatToIntRounding::Truncate
+    {
+        rounded = round_floats(from, options.float_to_int_rounding)?;
+        &rounded
+    } else {
+        from
+    };
+    let result = match options.overflow {
+        OverflowMode::Null => numeric_cast::<FROM, TO>(from),
+        OverflowMode::Saturate => numeric_saturating_cast::<FROM, TO>(from),
+        OverflowMode::Error => {
+            let (to, overflows) = cast_checked::<FROM, TO>(from)?;
+            match overflows.first() {
+                Some(&i) => Err(overflow_error(i, from.value(i), &TO::get_data_type())),
+                None => Ok(to),
+            }
+        }
+    };
+    match result {
         Ok(to) => Ok(Arc::new(to) as ArrayRef),
         Err(e) => Err(e),
     }
 }
 
-/// Natural cast between numeric types
-fn numeric_cast<T, R>(from: &PrimitiveArray<T>) -> Result<PrimitiveArray<R>>
-where
-    T: ArrowNumericType,
-    R: ArrowNumericType,
-    T::Native: num::NumCast,
-    R::Native: num::NumCast,
-{
-    let mut b = PrimitiveBuilder::<R>::new(from.len());
+/// Creates a primitive array of `len` nulls, without appending them one by one
+fn null_primitive_array<T: ArrowPrimitiveType>(len: usize) -> ArrayRef {
+    let value_bytes = len * std::mem::size_of::<T::Native>();
+    let null_bytes = bit_util::ceil(len, 8);
+    let array_data = ArrayData::new(
+        T::get_data_type(),
+        len,
+        Some(len),
+        Some(
+            MutableBuffer::new(null_bytes)
+                .with_bitset(null_bytes, false)
+                .freeze(),
+        ),
+        0,
+        vec![MutableBuffer::new(value_bytes)
+            .with_bitset(value_bytes, false)
+            .freeze()],
+        vec![],
+    );
+    make_array(Arc::new(array_data))
+}
+
+/// Creates a Boolean array of `len` nulls, without appending them one by one
+fn null_boolean_array(len: usize) -> ArrayRef {
+    let num_bytes = bit_util::ceil(len, 8);
+    let zeroed = || {
+        MutableBuffer::new(num_bytes)
+            .with_bitset(num_bytes, false)
+            .freeze()
+    };
+    let array_data = ArrayData::new(
+        DataType::Boolean,
+        len,
+        Some(len),
+        Some(zeroed()),
+        0,
+        vec![zeroed()],
+        vec![],
+    );
+    make_array(Arc::new(array_data))
+}
+
+/// Creates a Utf8 array of `len` nulls, all of them empty, without appending them one
+/// by one
+fn null_utf8_array(len: usize) -> ArrayRef {
+    let null_bytes = bit_util::ceil(len, 8);
+    let offset_bytes = (len + 1) * std::mem::size_of::<i32>();
+    let array_data = ArrayData::new(
+        DataType::Utf8,
+        len,
+        Some(len),
+        Some(
+            MutableBuffer::new(null_bytes)
+                .with_bitset(null_bytes, false)
+                .freeze(),
+        ),
+        0,
+        vec![
+            MutableBuffer::new(offset_bytes)
+                .with_bitset(offset_bytes, false)
+                .freeze(),
+            MutableBuffer::new(0).freeze(),
+        ],
+        vec![],
+    );
+    make_array(Arc::new(array_data))
+}
 
+/// Checks that every value of a Float64 array is exactly representable as a Float32
+fn check_f32_precision(array: &ArrayRef) -> Result<()> {
+    let from = array.as_any().downcast_ref::<Float64Array>().unwrap();
     for i in 0..from.len() {
-        if from.is_null(i) {
-            b.append_null()?;
-        } else {
-            // some casts return None, such as a negative value to u{8|16|32|64}
-            match num::cast::cast(from.value(i)) {
-                Some(v) => b.append_value(v)?,
-                None => b.append_null()?,
-            };
+        let value = from.value(i);
+        if from.is_valid(i) && !value.is_nan() && f64::from(value as f32) != value {
+            return Err(ArrowError::ComputeError(format!(
+                "Value {:?} at index {} can't be represented exactly as Float32",
+                value, i
+            )));
         }
     }
-
-    Ok(b.finish())
+    Ok(())
 }
 
-/// Cast numeric types to Utf8
-fn cast_numeric_to_string<FROM>(array: &ArrayRef) -> Result<ArrayRef>
-where
-    FROM: ArrowNumericType,
-    FROM::Native: ::std::string::ToString,
-{
-    match numeric_to_string_cast::<FROM>(
-        array
-            .as_any()
-            .downcast_ref::<PrimitiveArray<FROM>>()
-            .unwrap(),
-    ) {
-        Ok(to) => Ok(Arc::new(to) as ArrayRef),
-        Err(e) => Err(e),
+fn is_float(data_type: &DataType) -> bool {
+    match data_type {
+        DataType::Float32 | DataType::Float64 => true,
+        _ => false,
     }
 }
 
-fn numeric_to_string_cast<T>(from: &PrimitiveArray<T>) -> Result<BinaryArray>
+/// Rounds the values of a float array to integral values
+fn round_floats<T>(
+    from: &PrimitiveArray<T>,
+    rounding: FloatToIntRounding,
+) -> Result<PrimitiveArray<T>>
 where
-    T: ArrowPrimitiveType + ArrowNumericType,
-    T::Native: ::std::string::ToString,
+    T: ArrowNumericType,
+    T::Native: num::NumCast,
 {
-    let mut b = BinaryBuilder::new(from.len());
+    let mut b = PrimitiveBuilder::<T>::new(from.len());
 
     for i in 0..from.len() {
         if from.is_null(i) {
-            b.append(false)?;
+            b.append_null()?;
         } else {
-            b.append_string(from.value(i).to_string().as_str())?;
+            // both float types are exactly representable as f64
+            let rounded = num::cast::cast::<T::Native, f64>(from.value(i))
+                .map(|v| match rounding {
+                    FloatToIntRounding::Truncate => v.trunc(),
+                    FloatToIntRounding::Round => round_half_to_even(v),
+                    FloatToIntRounding::Floor => v.floor(),
+                    FloatToIntRounding::Ceil => v.ceil(),
+                })
+                .and_then(num::cast::cast::<f64, T::Native>);
+            b.append_option(rounded)?;
         }
     }
 
     Ok(b.finish())
 }
 
-/// Cast numeric types to Utf8
-fn cast_string_to_numeric<TO>(from: &ArrayRef) -> Result<ArrayRef>
-where
-    TO: ArrowNumericType,
-{
-    match string_to_numeric_cast::<TO>(
-        from.as_any().downcast_ref::<BinaryArray>().unwrap(),
-    ) {
-        Ok(to) => Ok(Arc::new(to) as ArrayRef),
-        Err(e) => Err(e),
+/// Rounds to the nearest integer, and halfway cases to the nearest even integer
+fn round_half_to_even(value: f64) -> f64 {
+    if (value - value.trunc()).abs() == 0.5 {
+        2.0 * (value / 2.0).round()
+    } else {
+        value.round()
+    }
+}
+
+/// Divides an integer by a positive divisor, rounding the quotient per `rounding`
+fn divide_rounded(value: i64, divisor: i64, rounding: FloatToIntRounding) -> i64 {
+    let (quotient, remainder) = (value / divisor, value % divisor);
+    let away_from_zero = match rounding {
+        FloatToIntRounding::Truncate => false,
+        FloatToIntRounding::Floor => remainder < 0,
+        FloatToIntRounding::Ceil => remainder > 0,
+        FloatToIntRounding::Round => {
+            let twice = 2 * remainder.abs();
+            twice > divisor || (twice == divisor && quotient % 2 != 0)
+        }
+    };
+    if away_from_zero {
+        quotient + remainder.signum()
+    } else {
+        quotient
     }
 }
 
-fn string_to_numeric_cast<T>(from: &BinaryArray) -> Result<PrimitiveArray<T>>
+/// Replaces all NaNs of a float array with the canonical quiet NaN
+fn canonicalize_nans<T>(array: &ArrayRef) -> Result<ArrayRef>
 where
     T: ArrowNumericType,
-    // T::Native: ::std::string::ToString,
+    T::Native: num::Float,
 {
+    let from = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
     let mut b = PrimitiveBuilder::<T>::new(from.len());
 
     for i in 0..from.len() {
         if from.is_null(i) {
             b.append_null()?;
+        } else if num::Float::is_nan(from.value(i)) {
+            b.append_value(<T::Native as num::Float>::nan())?;
         } else {
-            match std::str::from_utf8(from.value(i))
-                .unwrap_or("")
-                .parse::<T::Native>()
-            {
-                Ok(v) => b.append_value(v)?,
-                _ => b.append_null()?,
-            };
+            b.append_value(from.value(i))?;
         }
     }
 
-    Ok(b.finish())
+    Ok(Arc::new(b.finish()) as ArrayRef)
 }
 
-/// Cast numeric types to Boolean
-///
-/// Any zero value returns `false` while non-zero returns `true`
-fn cast_numeric_to_bool<FROM>(from: &ArrayRef) -> Result<ArrayRef>
-where
-    FROM: ArrowNumericType,
-{
-    match numeric_to_bool_cast::<FROM>(
-        from.as_any()
-            .downcast_ref::<PrimitiveArray<FROM>>()
-            .unwrap(),
-    ) {
-        Ok(to) => Ok(Arc::new(to) as ArrayRef),
-        Err(e) => Err(e),
-    }
+fn overflow_error<N: std::fmt::Debug>(
+    i: usize,
+    value: N,
+    to_type: &DataType,
+) -> ArrowError {
+    ArrowError::ComputeError(format!(
+        "Value {:?} at index {} does not fit into {:?}",
+        value, i, to_type
+    ))
 }
 
-fn numeric_to_bool_cast<T>(from: &PrimitiveArray<T>) -> Result<BooleanArray>
-where
-    T: ArrowPrimitiveType + ArrowNumericType,
-{
-    let mut b = BooleanBuilder::new(from.len());
+/// Cast Timestamp between time units
+///
+/// Converting to a coarser unit rounds values per `float_to_int_rounding`, converting to
+/// a finer unit can overflow.
+fn cast_timestamp_unit(
+    array: &ArrayRef,
+    from_unit: &TimeUnit,
+    to_unit: &TimeUnit,
+    options: &CastOptions,
+) -> Result<ArrayRef> {
+    let to_type = DataType::Timestamp(to_unit.clone());
+    if array.null_count() == array.len() {
+        let nulls = null_primitive_array::<Int64Type>(array.len());
+        return Ok(reinterpret_array(&nulls, &to_type));
+    }
+    let from_size = time_unit_nanoseconds(from_unit);
+    let to_size = time_unit_nanoseconds(to_unit);
+    // timestamps of all units are stored as i64
+    let from = Int64Array::from(array.data());
+    let mut b = Int64Builder::new(from.len());
 
     for i in 0..from.len() {
         if from.is_null(i) {
             b.append_null()?;
+            continue;
+        }
+        let value = from.value(i);
+        let converted = if from_size >= to_size {
+            value.checked_mul(from_size / to_size)
         } else {
-            if from.value(i) != T::default_value() {
-                b.append_value(true)?;
+            Some(divide_rounded(
+                value,
+                to_size / from_size,
+                options.float_to_int_rounding,
+            ))
+        };
+        match (converted, options.overflow) {
+            (Some(v), _) => b.append_value(v)?,
+            (None, OverflowMode::Null) => b.append_null()?,
+            (None, OverflowMode::Saturate) => b.append_value(if value < 0 {
+                std::i64::MIN
             } else {
-                b.append_value(false)?;
+                std::i64::MAX
+            })?,
+            (None, OverflowMode::Error) => {
+                return Err(overflow_error(i, value, &to_type))
             }
         }
     }
 
-    Ok(b.finish())
+    let data = b.finish().data();
+    let array_data = ArrayData::new(
+        to_type,
+        data.len(),
+        Some(data.null_count()),
+        data.null_bitmap().clone().map(|bitmap| bitmap.bits),
+        0,
+        data.buffers().to_vec(),
+        vec![],
+    );
+    Ok(make_array(Arc::new(array_data)))
 }
 
-/// Cast Boolean types to numeric
-///
-/// `false` returns 0 while `true` returns 1
-fn cast_bool_to_numeric<TO>(from: &ArrayRef) -> Result<ArrayRef>
+/// Cast integers to a time of day, checking that they are within a day
+fn cast_int_to_time<FROM>(
+    array: &ArrayRef,
+    to_type: &DataType,
+    options: &CastOptions,
+) -> Result<ArrayRef>
 where
-    TO: ArrowNumericType,
-    TO::Native: num::cast::NumCast,
+    FROM: ArrowNumericType,
+    FROM::Native: num::NumCast,
 {
-    match bool_to_numeric_cast::<TO>(
-        from.as_any().downcast_ref::<BooleanArray>().unwrap(),
-    ) {
-        Ok(to) => Ok(Arc::new(to) as ArrayRef),
-        Err(e) => Err(e),
+    let unit = match to_type {
+        DataType::Time32(unit) | DataType::Time64(unit) => unit,
+        _ => unreachable!(),
+    };
+    if array.null_count() == array.len() {
+        let nulls = null_primitive_array::<FROM>(array.len());
+        return Ok(reinterpret_array(&nulls, to_type));
+    }
+    let day =
+        86_400 * time_unit_nanoseconds(&TimeUnit::Second) / time_unit_nanoseconds(unit);
+    let from = array
+        .as_any()
+        .downcast_ref::<PrimitiveArray<FROM>>()
+        .unwrap();
+    let mut b = PrimitiveBuilder::<FROM>::new(from.len());
+
+    for i in 0..from.len() {
+        if from.is_null(i) {
+            b.append_null()?;
+            continue;
+        }
+        let value = from.value(i);
+        match num::cast::cast::<FROM::Native, i64>(value) {
+            Some(v) if v >= 0 && v < day => b.append_value(value)?,
+            _ if options.overflow == OverflowMode::Error => {
+                return Err(overflow_error(i, value, to_type));
+            }
+            _ => b.append_null()?,
+        }
     }
+
+    let data = b.finish().data();
+    let array_data = ArrayData::new(
+        to_type.clone(),
+        data.len(),
+        Some(data.null_count()),
+        data.null_bitmap().clone().map(|bitmap| bitmap.bits),
+        0,
+        data.buffers().to_vec(),
+        vec![],
+    );
+    Ok(make_array(Arc::new(array_data)))
 }
 
-fn bool_to_numeric_cast<T>(from: &BooleanArray) -> Result<PrimitiveArray<T>>
+/// Cast the integer values of a Time32 or Time64 array to Utf8, formatting them as
+/// `HH:MM:SS` with as many fractional digits as `unit` has
+///
+/// Values outside of a day are null.
+fn cast_time_to_string<FROM>(array: &ArrayRef, unit: &TimeUnit) -> Result<ArrayRef>
+where
+    FROM: ArrowNumericType,
+    FROM::Native: num::NumCast,
+{
+    if array.null_count() == array.len() {
+        return Ok(null_utf8_array(array.len()));
+    }
+    let unit_nanoseconds = time_unit_nanoseconds(unit);
+    let per_second = time_unit_nanoseconds(&TimeUnit::Second) / unit_nanoseconds;
+    let format = match unit {
+        TimeUnit::Second => "%H:%M:%S",
+        TimeUnit::Millisecond => "%H:%M:%S%.3f",
+        TimeUnit::Microsecond => "%H:%M:%S%.6f",
+        TimeUnit::Nanosecond => "%H:%M:%S%.9f",
+    };
+    let from = array
+        .as_any()
+        .downcast_ref::<PrimitiveArray<FROM>>()
+        .unwrap();
+    let mut b = BinaryBuilder::new(from.len());
+
+    for i in 0..from.len() {
+        if from.is_null(i) {
+            b.append(false)?;
+            continue;
+        }
+        let time = num::cast::cast::<FROM::Native, i64>(from.value(i))
+            .filter(|v| *v >= 0 && *v < 86_400 * per_second)
+            .and_then(|v| {
+                NaiveTime::from_num_seconds_from_midnight_opt(
+                    (v / per_second) as u32,
+                    ((v % per_second) * unit_nanoseconds) as u32,
+                )
+            });
+        match time {
+            Some(time) => b.append_string(&time.format(format).to_string())?,
+            None => b.append(false)?,
+        }
+    }
+
+    Ok(Arc::new(b.finish()) as ArrayRef)
+}
+
+/// Cast Utf8 to Time32 or Time64, parsing values as `HH:MM:SS` with optional fractional
+/// seconds
+///
+/// Fractional digits finer than the time unit are truncated. Strings that can't be
+/// parsed are null, and values outside of a day are handled like in integer casts.
+fn cast_string_to_time(
+    array: &ArrayRef,
+    to_type: &DataType,
+    options: &CastOptions,
+) -> Result<ArrayRef> {
+    let unit = match to_type {
+        DataType::Time32(unit) | DataType::Time64(unit) => unit,
+        _ => unreachable!(),
+    };
+    let unit_nanoseconds = time_unit_nanoseconds(unit);
+    let per_second = time_unit_nanoseconds(&TimeUnit::Second) / unit_nanoseconds;
+    let from = array.as_any().downcast_ref::<BinaryArray>().unwrap();
+    let mut b = Int64Builder::new(from.len());
+
+    for i in 0..from.len() {
+        if from.is_null(i) {
+            b.append_null()?;
+            continue;
+        }
+        let time = utf8_value(from, i)
+            .and_then(|value| NaiveTime::parse_from_str(value, "%H:%M:%S%.f").ok());
+        b.append_option(time.map(|time| {
+            i64::from(time.num_seconds_from_midnight()) * per_second
+                + i64::from(time.nanosecond()) / unit_nanoseconds
+        }))?;
+    }
+
+    let values = Arc::new(b.finish()) as ArrayRef;
+    match to_type {
+        DataType::Time32(_) => {
+            // times of day in seconds or milliseconds always fit in an i32, and the
+            // caller's options are not meant for this internal step
+            let values =
+                cast_with_options(&values, &DataType::Int32, &CastOptions::default())?;
+            cast_int_to_time::<Int32Type>(&values, to_type, options)
+        }
+        _ => cast_int_to_time::<Int64Type>(&values, to_type, options),
+    }
+}
+
+/// Number of nanoseconds in a time unit
+fn time_unit_nanoseconds(unit: &TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Second => 1_000_000_000,
+        TimeUnit::Millisecond => 1_000_000,
+        TimeUnit::Microsecond => 1_000,
+        TimeUnit::Nanosecond => 1,
+    }
+}
+
+/// Natural cast between numeric types
+fn numeric_cast<T, R>(from: &PrimitiveArray<T>) -> Result<PrimitiveArray<R>>
+where
+    T: ArrowNumericType,
+    R: ArrowNumericType,
+    T::Native: num::NumCast,
+    R::Native: num::NumCast,
+{
+    let mut b = PrimitiveBuilder::<R>::new(from.len());
+
+    for i in 0..from.len() {
+        if from.is_null(i) {
+            b.append_null()?;
+        } else {
+            // some casts return None, such as a negative value to u{8|16|32|64}
+            match num::cast::cast(from.value(i)) {
+                Some(v) => b.append_value(v)?,
+                None => b.append_null()?,
+            };
+        }
+    }
+
+    Ok(b.finish())
+}
+
+/// Cast between numeric types, also returning the indices of values that overflowed
+///
+/// Values that don't fit into `TO` are null in the output, like with `cast`. Their
+/// indices are returned in ascending order; nulls in the input are not overflows.
+pub fn cast_checked<FROM, TO>(
+    array: &PrimitiveArray<FROM>,
+) -> Result<(PrimitiveArray<TO>, Vec<usize>)>
+where
+    FROM: ArrowNumericType,
+    TO: ArrowNumericType,
+    FROM::Native: num::NumCast,
+    TO::Native: num::NumCast,
+{
+    let mut b = PrimitiveBuilder::<TO>::new(array.len());
+    let mut overflows = vec![];
+
+    for i in 0..array.len() {
+        if array.is_null(i) {
+            b.append_null()?;
+        } else {
+            match num::cast::cast(array.value(i)) {
+                Some(v) => b.append_value(v)?,
+                None => {
+                    overflows.push(i);
+                    b.append_null()?;
+                }
+            };
+        }
+    }
+
+    Ok((b.finish(), overflows))
+}
+
+/// Cast between numeric types, clamping out of range values to the bounds of `TO`,
+/// also returning a mask of the values that were clamped
+///
+/// The mask has one non-null slot per input value, and is `true` where a value didn't
+/// fit into `TO`. Nulls in the input, and NaNs, which are null in the output, are not
+/// clamped.
+pub fn cast_saturating_with_flags<FROM, TO>(
+    array: &PrimitiveArray<FROM>,
+) -> Result<(PrimitiveArray<TO>, BooleanArray)>
+where
+    FROM: ArrowNumericType,
+    TO: ArrowNumericType,
+    FROM::Native: num::NumCast + num::Bounded,
+    TO::Native: num::NumCast + num::Bounded,
+{
+    let values = numeric_saturating_cast::<FROM, TO>(array)?;
+    let clamped: Vec<bool> = (0..array.len())
+        .map(|i| {
+            values.is_valid(i)
+                && num::cast::cast::<FROM::Native, TO::Native>(array.value(i)).is_none()
+        })
+        .collect();
+
+    Ok((values, BooleanArray::from(clamped)))
+}
+
+/// Cast between numeric types with the semantics of `as`, also returning a mask of
+/// the values that wrapped
+///
+/// Integers that don't fit into `TO` keep their low bits, e.g. 300 becomes 44 in a
+/// `UInt8`. The mask has one non-null slot per input value, and is `true` where a value
+/// didn't fit into `TO`.
+pub fn cast_wrapping_with_flags<FROM, TO>(
+    array: &PrimitiveArray<FROM>,
+) -> Result<(PrimitiveArray<TO>, BooleanArray)>
+where
+    FROM: ArrowNumericType,
+    TO: ArrowNumericType,
+    FROM::Native: num::NumCast + num::traits::AsPrimitive<TO::Native>,
+    TO::Native: num::NumCast,
+{
+    let mut b = PrimitiveBuilder::<TO>::new(array.len());
+    let mut wrapped = Vec::with_capacity(array.len());
+
+    for i in 0..array.len() {
+        if array.is_null(i) {
+            b.append_null()?;
+            wrapped.push(false);
+        } else {
+            let value = array.value(i);
+            b.append_value(num::traits::AsPrimitive::as_(value))?;
+            wrapped.push(num::cast::cast::<FROM::Native, TO::Native>(value).is_none());
+        }
+    }
+
+    Ok((b.finish(), BooleanArray::from(wrapped)))
+}
+
+/// Cast between numeric types, applying `value * scale + offset` to each value
+///
+/// The transform is computed in `f64`, and the result is converted to `TO` with the
+/// rounding and overflow behavior of `options`, e.g. to convert temperatures from
+/// Celsius to Fahrenheit with a `scale` of `1.8` and an `offset` of `32`.
+pub fn cast_affine<FROM, TO>(
+    array: &PrimitiveArray<FROM>,
+    scale: f64,
+    offset: f64,
+    options: &CastOptions,
+) -> Result<PrimitiveArray<TO>>
+where
+    FROM: ArrowNumericType,
+    TO: ArrowNumericType,
+    FROM::Native: num::NumCast,
+    TO::Native: num::NumCast + num::Bounded,
+{
+    let mut b = PrimitiveBuilder::<Float64Type>::new(array.len());
+
+    for i in 0..array.len() {
+        if array.is_null(i) {
+            b.append_null()?;
+        } else {
+            let value = num::ToPrimitive::to_f64(&array.value(i));
+            b.append_option(value.map(|v| v * scale + offset))?;
+        }
+    }
+
+    let transformed = Arc::new(b.finish()) as ArrayRef;
+    let to = cast_numeric_arrays::<Float64Type, TO>(&transformed, options)?;
+    Ok(PrimitiveArray::<TO>::from(to.data()))
+}
+
+/// Cast between numeric types, clamping out of range values to the bounds of `R`
+fn numeric_saturating_cast<T, R>(from: &PrimitiveArray<T>) -> Result<PrimitiveArray<R>>
+where
+    T: ArrowNumericType,
+    R: ArrowNumericType,
+    T::Native: num::NumCast + num::Bounded,
+    R::Native: num::NumCast + num::Bounded,
+{
+    // the range of `R`, expressed in `T`. A bound of `R` that `T` can't represent lies
+    // beyond the matching bound of `T`.
+    let lower = num::cast::cast(<R::Native as num::Bounded>::min_value())
+        .unwrap_or(<T::Native as num::Bounded>::min_value());
+    let upper = num::cast::cast(<R::Native as num::Bounded>::max_value())
+        .unwrap_or(<T::Native as num::Bounded>::max_value());
+    // infinities are floats too, so float targets keep them instead of clamping them
+    let clamped = clamp_values(from, lower, upper, is_float(&R::get_data_type()));
+
+    let mut b = PrimitiveBuilder::<R>::new(from.len());
+
+    for (i, &value) in clamped.iter().enumerate() {
+        if from.is_null(i) {
+            b.append_null()?;
+        } else {
+            b.append_option(saturate::<T, R>(from.value(i), value))?;
+        }
+    }
+
+    Ok(b.finish())
+}
+
+/// Converts a value that was clamped into the range of `R`
+///
+/// The float bounds of some integer types are not exactly representable, e.g.
+/// `i64::MAX as f64` is larger than `i64::MAX`, so clamped values can still fail to
+/// convert. These fall back to the bound on the side of the original value.
+fn saturate<T, R>(value: T::Native, clamped: T::Native) -> Option<R::Native>
+where
+    T: ArrowNumericType,
+    R: ArrowNumericType,
+    T::Native: num::NumCast,
+    R::Native: num::NumCast + num::Bounded,
+{
+    // NaN is the only value that is not comparable to itself
+    value.partial_cmp(&value)?;
+    num::cast::cast(clamped).or_else(|| {
+        if value < T::default_value() {
+            Some(<R::Native as num::Bounded>::min_value())
+        } else {
+            Some(<R::Native as num::Bounded>::max_value())
+        }
+    })
+}
+
+/// Clamps the values of an array to `[lower, upper]`, ignoring its null bitmap
+///
+/// With `keep_infinite`, infinite values are left as they are.
+fn clamp_values<T>(
+    from: &PrimitiveArray<T>,
+    lower: T::Native,
+    upper: T::Native,
+    keep_infinite: bool,
+) -> Vec<T::Native>
+where
+    T: ArrowNumericType,
+    T::Native: num::Bounded,
+{
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    return simd_clamp_values(from, lower, upper, keep_infinite);
+
+    #[allow(unreachable_code)]
+    scalar_clamp_values(from, lower, upper, keep_infinite)
+}
+
+fn scalar_clamp_values<T>(
+    from: &PrimitiveArray<T>,
+    lower: T::Native,
+    upper: T::Native,
+    keep_infinite: bool,
+) -> Vec<T::Native>
+where
+    T: ArrowNumericType,
+    T::Native: num::Bounded,
+{
+    from.value_slice(0, from.len())
+        .iter()
+        .map(|&v| clamp(v, lower, upper, keep_infinite))
+        .collect()
+}
+
+/// Returns whether `value` is infinite. Only floats have values beyond their bounds.
+fn is_infinite<N: PartialOrd + num::Bounded>(value: N) -> bool {
+    value < N::min_value() || value > N::max_value()
+}
+
+fn clamp<N: PartialOrd + Copy + num::Bounded>(
+    value: N,
+    lower: N,
+    upper: N,
+    keep_infinite: bool,
+) -> N {
+    if keep_infinite && is_infinite(value) {
+        value
+    } else if value < lower {
+        lower
+    } else if value > upper {
+        upper
+    } else {
+        value
+    }
+}
+
+/// SIMD vectorized version of `scalar_clamp_values` above.
+///
+/// Full chunks of lanes are clamped with SIMD min/max, the remainder with scalar code.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn simd_clamp_values<T>(
+    from: &PrimitiveArray<T>,
+    lower: T::Native,
+    upper: T::Native,
+    keep_infinite: bool,
+) -> Vec<T::Native>
+where
+    T: ArrowNumericType,
+    T::Native: num::Bounded,
+{
+    let lanes = T::lanes();
+    let mut result = vec![T::default_value(); from.len()];
+
+    let simd_len = from.len() - from.len() % lanes;
+    for i in (0..simd_len).step_by(lanes) {
+        let simd_values = T::load(from.value_slice(i, lanes));
+        let simd_result = T::min(T::max(simd_values, T::init(lower)), T::init(upper));
+        T::write(simd_result, &mut result[i..i + lanes]);
+        if keep_infinite {
+            let values = from.value_slice(i, lanes);
+            for (clamped, &value) in result[i..i + lanes].iter_mut().zip(values) {
+                if is_infinite(value) {
+                    *clamped = value;
+                }
+            }
+        }
+    }
+    for (i, value) in result.iter_mut().enumerate().skip(simd_len) {
+        *value = clamp(from.value(i), lower, upper, keep_infinite);
+    }
+
+    result
+}
+
+/// Adds `shift` to each value of a date array, nulling values that overflow
+fn shift_dates<T>(array: &ArrayRef, shift: i64) -> Result<ArrayRef>
 where
     T: ArrowNumericType,
     T::Native: num::NumCast,
 {
+    // shifting only nulls leaves them as they are
+    if array.null_count() == array.len() {
+        return Ok(array.clone());
+    }
+    let from = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
     let mut b = PrimitiveBuilder::<T>::new(from.len());
 
     for i in 0..from.len() {
         if from.is_null(i) {
             b.append_null()?;
         } else {
-            if from.value(i) {
-                // a workaround to cast a primitive to T::Native, infallible
-                match num::cast::cast(1) {
-                    Some(v) => b.append_value(v)?,
-                    None => b.append_null()?,
-                };
+            let shifted = num::cast::cast::<T::Native, i64>(from.value(i))
+                .and_then(|v| v.checked_add(shift))
+                .and_then(num::cast::cast::<i64, T::Native>);
+            b.append_option(shifted)?;
+        }
+    }
+
+    Ok(Arc::new(b.finish()) as ArrayRef)
+}
+
+/// Cast numeric types to Utf8
+fn cast_numeric_to_string<FROM>(
+    array: &ArrayRef,
+    options: &CastOptions,
+) -> Result<ArrayRef>
+where
+    FROM: ArrowNumericType,
+    FROM::Native: std::fmt::Display + std::ops::Mul<Output = FROM::Native> + num::NumCast,
+{
+    if array.null_count() == array.len() {
+        return Ok(null_utf8_array(array.len()));
+    }
+    match numeric_to_string_cast::<FROM>(
+        array
+            .as_any()
+            .downcast_ref::<PrimitiveArray<FROM>>()
+            .unwrap(),
+        options,
+    ) {
+        Ok(to) => Ok(Arc::new(to) as ArrayRef),
+        Err(e) => Err(e),
+    }
+}
+
+fn numeric_to_string_cast<T>(
+    from: &PrimitiveArray<T>,
+    options: &CastOptions,
+) -> Result<BinaryArray>
+where
+    T: ArrowPrimitiveType + ArrowNumericType,
+    T::Native: std::fmt::Display + std::ops::Mul<Output = T::Native> + num::NumCast,
+{
+    let mut b = BinaryBuilder::new(from.len());
+    let hundred: T::Native = num::cast::cast(100).unwrap();
+
+    for i in 0..from.len() {
+        if from.is_null(i) {
+            b.append(false)?;
+            continue;
+        }
+        let mut value = from.value(i);
+        if options.normalize_negative_zero && value == T::default_value() {
+            // negative zero is equal to zero
+            value = T::default_value();
+        }
+        if options.format_percent {
+            value = value * hundred;
+        }
+        let mut formatted = match options.float_precision {
+            Some(precision) => format!("{:.*}", precision, value),
+            None => value.to_string(),
+        };
+        if options.format_percent {
+            formatted.push('%');
+        }
+        b.append_string(&formatted)?;
+    }
+
+    Ok(b.finish())
+}
+
+/// Cast integer types to Utf8, honoring the integer formatting options
+fn cast_int_to_string<FROM>(array: &ArrayRef, options: &CastOptions) -> Result<ArrayRef>
+where
+    FROM: ArrowNumericType,
+    FROM::Native: num::ToPrimitive + ::std::string::ToString,
+{
+    if array.null_count() == array.len() {
+        return Ok(null_utf8_array(array.len()));
+    }
+    match int_to_string_cast::<FROM>(
+        array
+            .as_any()
+            .downcast_ref::<PrimitiveArray<FROM>>()
+            .unwrap(),
+        options,
+    ) {
+        Ok(to) => Ok(Arc::new(to) as ArrayRef),
+        Err(e) => Err(e),
+    }
+}
+
+fn int_to_string_cast<T>(
+    from: &PrimitiveArray<T>,
+    options: &CastOptions,
+) -> Result<BinaryArray>
+where
+    T: ArrowNumericType,
+    T::Native: num::ToPrimitive + ::std::string::ToString,
+{
+    let radix = options.int_radix.unwrap_or(10);
+    if radix < 2 || radix > 36 {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Integer radix must be between 2 and 36, got {}",
+            radix
+        )));
+    }
+
+    let mut b = BinaryBuilder::new(from.len());
+
+    for i in 0..from.len() {
+        if from.is_null(i) {
+            b.append(false)?;
+        } else {
+            let mut value = if radix == 10 {
+                from.value(i).to_string()
+            } else {
+                format_int_radix(from.value(i), radix)
+            };
+            if let Some(width) = options.int_zero_pad {
+                value = zero_pad(&value, width);
+            }
+            match options.group_digits {
+                Some(separator) => b.append_string(&group_digits(&value, separator))?,
+                None => b.append_string(&value)?,
+            }
+        }
+    }
+
+    Ok(b.finish())
+}
+
+/// Formats an integer in the given radix, which must be between 2 and 36
+fn format_int_radix<N: num::ToPrimitive>(value: N, radix: u32) -> String {
+    // every integer type fits into either a u64 or a negative i64
+    let (negative, mut magnitude) = match value.to_u64() {
+        Some(v) => (false, v),
+        None => (true, value.to_i64().unwrap().wrapping_neg() as u64),
+    };
+
+    let mut digits = vec![];
+    loop {
+        digits.push(
+            std::char::from_digit((magnitude % radix as u64) as u32, radix).unwrap(),
+        );
+        magnitude /= radix as u64;
+        if magnitude == 0 {
+            break;
+        }
+    }
+    if negative {
+        digits.push('-');
+    }
+    digits.iter().rev().collect()
+}
+
+/// Left-pads a formatted integer with zeros to `width` characters, keeping its sign in
+/// front, e.g. `"-42"` => `"-0042"` with a width of 5
+fn zero_pad(value: &str, width: usize) -> String {
+    let (sign, digits) = if value.starts_with('-') {
+        value.split_at(1)
+    } else {
+        ("", value)
+    };
+    let zeros = width.saturating_sub(value.len());
+    format!("{}{}{}", sign, "0".repeat(zeros), digits)
+}
+
+/// Inserts `separator` between every 3 digits of a formatted integer, counting from
+/// the right, e.g. `"-1234"` => `"-1,234"`
+fn group_digits(value: &str, separator: char) -> String {
+    let (sign, digits) = if value.starts_with('-') {
+        value.split_at(1)
+    } else {
+        ("", value)
+    };
+    let mut grouped = sign.to_string();
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push(separator);
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+/// Cast Utf8 to numeric types
+fn cast_string_to_numeric<TO>(from: &ArrayRef, options: &CastOptions) -> Result<ArrayRef>
+where
+    TO: ArrowNumericType,
+    TO::Native: num::NumCast,
+{
+    if from.null_count() == from.len() {
+        return Ok(null_primitive_array::<TO>(from.len()));
+    }
+    match string_to_numeric_cast::<TO>(
+        from.as_any().downcast_ref::<BinaryArray>().unwrap(),
+        options,
+    ) {
+        Ok(to) => Ok(Arc::new(to) as ArrayRef),
+        Err(e) => Err(e),
+    }
+}
+
+fn string_to_numeric_cast<T>(
+    from: &BinaryArray,
+    options: &CastOptions,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: num::NumCast,
+{
+    let mut b = PrimitiveBuilder::<T>::new(from.len());
+    let is_float = is_float(&T::get_data_type());
+    let parse_percent = options.parse_percent && is_float;
+    let default = if is_float {
+        options.parse_default_float.and_then(num::cast::cast)
+    } else {
+        options.parse_default.and_then(num::cast::cast)
+    };
+
+    for i in 0..from.len() {
+        if from.is_null(i) {
+            b.append_null()?;
+        } else {
+            let mut value = ascii_or_utf8(from.value(i));
+            if options.strip_quotes {
+                value = strip_quotes(value);
+            }
+            let percent = parse_percent && value.ends_with('%');
+            if options.strip_nonnumeric_suffix && !percent {
+                value = strip_nonnumeric_suffix(value, options.decimal_separator);
+            }
+            let parsed = if percent {
+                parse_number::<f64>(&value[..value.len() - 1], options)
+                    .and_then(|v| num::cast::cast(v / 100.0))
+            } else {
+                parse_number::<T::Native>(value, options)
+            };
+            b.append_option(parsed.or(default))?;
+        }
+    }
+
+    Ok(b.finish())
+}
+
+/// Returns the value at `i` as a `&str`, or `None` if it isn't valid UTF-8
+fn utf8_value(from: &BinaryArray, i: usize) -> Option<&str> {
+    std::str::from_utf8(from.value(i)).ok()
+}
+
+/// Views a value as a `&str`, skipping UTF-8 validation when all of its bytes are
+/// ASCII. Values that aren't valid UTF-8 are viewed as an empty string.
+fn ascii_or_utf8(value: &[u8]) -> &str {
+    if value.is_ascii() {
+        // ASCII is always valid UTF-8
+        unsafe { std::str::from_utf8_unchecked(value) }
+    } else {
+        std::str::from_utf8(value).unwrap_or("")
+    }
+}
+
+/// Removes a single pair of double quotes around a value, e.g. `"\"42\""` => `"42"`
+fn strip_quotes(value: &str) -> &str {
+    if value.len() >= 2 && value.starts_with('"') && value.ends_with('"') {
+        &value[1..value.len() - 1]
+    } else {
+        value
+    }
+}
+
+/// Removes the trailing characters of a value that can't be part of a number, such as
+/// a unit, e.g. `"3.5kg"` => `"3.5"`
+fn strip_nonnumeric_suffix(value: &str, decimal_separator: char) -> &str {
+    value.trim_end_matches(|c: char| {
+        !(c.is_ascii_digit()
+            || c == '+'
+            || c == '-'
+            || c == '.'
+            || c == decimal_separator)
+    })
+}
+
+/// Parses a number written with the decimal separator of `options`
+fn parse_number<N: std::str::FromStr>(value: &str, options: &CastOptions) -> Option<N> {
+    let separator = options.decimal_separator;
+    if separator == '.' {
+        value.parse::<N>().ok()
+    } else {
+        swap_decimal_separator(value, separator).parse::<N>().ok()
+    }
+}
+
+/// Rewrites a number using `separator` as its decimal separator into one using `.`,
+/// dropping `.` thousands separators
+fn swap_decimal_separator(value: &str, separator: char) -> String {
+    value
+        .chars()
+        .filter(|c| *c != '.')
+        .map(|c| if c == separator { '.' } else { c })
+        .collect()
+}
+
+/// Cast nested arrays to Utf8, formatting each value as JSON
+fn cast_to_json(array: &ArrayRef) -> Result<ArrayRef> {
+    let mut b = BinaryBuilder::new(array.len());
+
+    for i in 0..array.len() {
+        if array.is_null(i) {
+            b.append(false)?;
+        } else {
+            b.append_string(&json_value(array, i)?.to_string())?;
+        }
+    }
+
+    Ok(Arc::new(b.finish()) as ArrayRef)
+}
+
+/// Converts the value at index `i` of an array to JSON
+fn json_value(array: &ArrayRef, i: usize) -> Result<Value> {
+    use DataType::*;
+    if array.is_null(i) {
+        return Ok(Value::Null);
+    }
+    match array.data_type() {
+        Boolean => {
+            let from = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            Ok(Value::Bool(from.value(i)))
+        }
+        UInt8 => Ok(json_number::<UInt8Type>(array, i)),
+        UInt16 => Ok(json_number::<UInt16Type>(array, i)),
+        UInt32 => Ok(json_number::<UInt32Type>(array, i)),
+        UInt64 => Ok(json_number::<UInt64Type>(array, i)),
+        Int8 => Ok(json_number::<Int8Type>(array, i)),
+        Int16 => Ok(json_number::<Int16Type>(array, i)),
+        Int32 => Ok(json_number::<Int32Type>(array, i)),
+        Int64 => Ok(json_number::<Int64Type>(array, i)),
+        Float32 => Ok(json_number::<Float32Type>(array, i)),
+        Float64 => Ok(json_number::<Float64Type>(array, i)),
+        Utf8 => {
+            let from = array.as_any().downcast_ref::<BinaryArray>().unwrap();
+            // values that aren't valid UTF-8 have no JSON string
+            Ok(utf8_value(from, i).map_or(Value::Null, |v| Value::String(v.to_string())))
+        }
+        Struct(fields) => {
+            let from = array.as_any().downcast_ref::<StructArray>().unwrap();
+            let mut object = Map::new();
+            for (j, field) in fields.iter().enumerate() {
+                object.insert(field.name().clone(), json_value(from.column(j), i)?);
+            }
+            Ok(Value::Object(object))
+        }
+        List(_) => {
+            let from = array.as_any().downcast_ref::<ListArray>().unwrap();
+            let values = from.values();
+            let start = from.value_offset(i) as usize;
+            let end = start + from.value_length(i) as usize;
+            let elements = (start..end)
+                .map(|j| json_value(&values, j))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(Value::Array(elements))
+        }
+        data_type => Err(ArrowError::CastNotSupported {
+            from: data_type.clone(),
+            to: Utf8,
+        }),
+    }
+}
+
+/// Converts a number to JSON. Non-finite floats have no JSON representation and
+/// become `null`.
+fn json_number<T>(array: &ArrayRef, i: usize) -> Value
+where
+    T: ArrowNumericType,
+    T::Native: num::ToPrimitive,
+{
+    let from = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    let value = from.value(i);
+    if is_float(&T::get_data_type()) {
+        num::ToPrimitive::to_f64(&value)
+            .and_then(Number::from_f64)
+            .map_or(Value::Null, Value::Number)
+    } else {
+        num::ToPrimitive::to_i64(&value)
+            .map(Value::from)
+            .or_else(|| num::ToPrimitive::to_u64(&value).map(Value::from))
+            .unwrap_or(Value::Null)
+    }
+}
+
+/// Parses Utf8 values as JSON arrays into a `List(Utf8)` of their elements
+///
+/// Strings are unquoted, nested arrays and objects are kept as JSON, and JSON `null`s
+/// are null.
+fn parse_json_lists(array: &ArrayRef) -> Result<ArrayRef> {
+    let from = array.as_any().downcast_ref::<BinaryArray>().unwrap();
+    let mut b = ListBuilder::new(BinaryBuilder::new(from.len()));
+
+    for i in 0..from.len() {
+        if from.is_null(i) {
+            b.append(false)?;
+            continue;
+        }
+        match utf8_value(from, i).map(serde_json::from_str) {
+            Some(Ok(Value::Array(elements))) => {
+                for element in elements {
+                    match element {
+                        Value::Null => b.values().append(false)?,
+                        Value::String(s) => b.values().append_string(&s)?,
+                        element => b.values().append_string(&element.to_string())?,
+                    }
+                }
+                b.append(true)?;
+            }
+            _ => b.append(false)?,
+        }
+    }
+
+    Ok(Arc::new(b.finish()) as ArrayRef)
+}
+
+/// Cast Utf8 to Utf8, rewriting each value as requested by `options`
+fn cast_utf8_to_utf8(array: &ArrayRef, options: &CastOptions) -> Result<ArrayRef> {
+    let from = array.as_any().downcast_ref::<BinaryArray>().unwrap();
+    if let Some(case) = options.ascii_case {
+        // values that aren't valid UTF-8 are null, which needs the per-value path
+        if options.pad.is_none()
+            && !options.url_decode
+            && !options.utf8_lossy
+            && options.unicode_normalize.is_none()
+            && options.strip_prefix.is_none()
+            && options.strip_suffix.is_none()
+            && (0..from.len()).all(|i| from.is_null(i) || utf8_value(from, i).is_some())
+        {
+            return Ok(ascii_case_bytes(array, case));
+        }
+    }
+    let mut b = BinaryBuilder::new(from.len());
+
+    for i in 0..from.len() {
+        if from.is_null(i) {
+            b.append(false)?;
+        } else {
+            let mut value = if options.utf8_lossy {
+                String::from_utf8_lossy(from.value(i)).into_owned()
+            } else {
+                match utf8_value(from, i) {
+                    Some(value) => value.to_string(),
+                    // values that aren't valid UTF-8 can't be rewritten as strings
+                    None => {
+                        b.append(false)?;
+                        continue;
+                    }
+                }
+            };
+            if options.url_decode {
+                value = match percent_decode(&value) {
+                    Ok(decoded) => decoded,
+                    Err(_) if options.url_decode_strict => {
+                        return Err(ArrowError::ComputeError(format!(
+                            "Invalid percent-encoding in {:?}",
+                            value
+                        )));
+                    }
+                    Err(partially_decoded) => partially_decoded,
+                };
+            }
+            if let Some(form) = options.unicode_normalize {
+                value = match form {
+                    NormalizationForm::Nfc => value.nfc().collect(),
+                    NormalizationForm::Nfd => value.nfd().collect(),
+                    NormalizationForm::Nfkc => value.nfkc().collect(),
+                    NormalizationForm::Nfkd => value.nfkd().collect(),
+                };
+            }
+            match options.ascii_case {
+                Some(Case::Upper) => value.make_ascii_uppercase(),
+                Some(Case::Lower) => value.make_ascii_lowercase(),
+                None => {}
+            }
+            if let Some(ref prefix) = options.strip_prefix {
+                if value.starts_with(prefix.as_str()) {
+                    value = value[prefix.len()..].to_string();
+                }
+            }
+            if let Some(ref suffix) = options.strip_suffix {
+                if value.ends_with(suffix.as_str()) {
+                    value.truncate(value.len() - suffix.len());
+                }
+            }
+            if let Some((width, fill, side)) = options.pad {
+                value = pad_string(value, width, fill, side);
+            }
+            b.append_string(&value)?;
+        }
+    }
+
+    Ok(Arc::new(b.finish()) as ArrayRef)
+}
+
+/// Converts the ASCII letters of Utf8 values to `case` a byte at a time, sharing the
+/// offsets and null bitmap of `array`
+///
+/// Bytes of multi-byte UTF-8 characters are never ASCII, so they are left untouched.
+fn ascii_case_bytes(array: &ArrayRef, case: Case) -> ArrayRef {
+    let data = array.data_ref();
+    let values = data.buffers()[1].data();
+    let values: Vec<u8> = match case {
+        Case::Upper => values.iter().map(u8::to_ascii_uppercase).collect(),
+        Case::Lower => values.iter().map(u8::to_ascii_lowercase).collect(),
+    };
+    let array_data = ArrayData::new(
+        DataType::Utf8,
+        array.len(),
+        Some(array.null_count()),
+        data.null_bitmap().clone().map(|bitmap| bitmap.bits),
+        array.offset(),
+        vec![data.buffers()[0].clone(), Buffer::from(values)],
+        vec![],
+    );
+    make_array(Arc::new(array_data))
+}
+
+/// Decodes `%XX` sequences of a string
+///
+/// Returns an error with the value decoded as far as possible if it has invalid
+/// sequences, which are kept as they are. Values that decode to invalid UTF-8 are
+/// returned unchanged as an error.
+fn percent_decode(value: &str) -> std::result::Result<String, String> {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut valid = true;
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = value
+                .get(i + 1..i + 3)
+                .filter(|hex| hex.bytes().all(|b| b.is_ascii_hexdigit()));
+            match hex.and_then(|hex| u8::from_str_radix(hex, 16).ok()) {
+                Some(byte) => {
+                    decoded.push(byte);
+                    i += 3;
+                    continue;
+                }
+                None => valid = false,
+            }
+        }
+        decoded.push(bytes[i]);
+        i += 1;
+    }
+    match String::from_utf8(decoded) {
+        Ok(decoded) if valid => Ok(decoded),
+        Ok(decoded) => Err(decoded),
+        Err(_) => Err(value.to_string()),
+    }
+}
+
+/// Pads `value` with `fill` on `side` until it is `width` characters wide
+fn pad_string(value: String, width: usize, fill: char, side: PadSide) -> String {
+    let len = value.chars().count();
+    if len >= width {
+        return value;
+    }
+    let padding: String = std::iter::repeat(fill).take(width - len).collect();
+    match side {
+        PadSide::Left => padding + &value,
+        PadSide::Right => value + &padding,
+    }
+}
+
+/// Cast numeric types to Boolean
+///
+/// Any zero value returns `false` while non-zero returns `true`
+fn cast_numeric_to_bool<FROM>(from: &ArrayRef, options: &CastOptions) -> Result<ArrayRef>
+where
+    FROM: ArrowNumericType,
+    FROM::Native: num::ToPrimitive,
+{
+    if let Some(bit) = options.bool_bit {
+        let width = 8 * std::mem::size_of::<FROM::Native>() as u32;
+        if is_float(&FROM::get_data_type()) || bit >= width {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot test bit {} of {:?} values",
+                bit,
+                FROM::get_data_type()
+            )));
+        }
+    }
+    if from.null_count() == from.len() {
+        return Ok(null_boolean_array(from.len()));
+    }
+    let from = from
+        .as_any()
+        .downcast_ref::<PrimitiveArray<FROM>>()
+        .unwrap();
+    let result = match options.bool_bit {
+        None => numeric_to_bool_cast(from, options.constant_hint, |v| {
+            v != FROM::default_value()
+        }),
+        Some(bit) => numeric_to_bool_cast(from, options.constant_hint, |v| {
+            (integer_bits(v) >> bit) & 1 == 1
+        }),
+    };
+    match result {
+        Ok(to) => Ok(Arc::new(to) as ArrayRef),
+        Err(e) => Err(e),
+    }
+}
+
+/// The bits of an integer in two's complement, sign extended to 64 bits
+fn integer_bits<N: num::ToPrimitive>(value: N) -> u64 {
+    value
+        .to_u64()
+        .unwrap_or_else(|| value.to_i64().unwrap() as u64)
+}
+
+/// Returns the null bitmap of `array` starting at its first slot, sharing the bitmap
+/// when the offset of `array` is a multiple of 8
+fn null_bitmap_from_start(array: &dyn Array) -> Option<Buffer> {
+    let offset = array.offset();
+    array.data_ref().null_bitmap().as_ref().map(|bitmap| {
+        if offset % 8 == 0 {
+            bitmap.bits.slice(offset / 8)
+        } else {
+            let num_bytes = bit_util::ceil(array.len(), 8);
+            let mut nulls = MutableBuffer::new(num_bytes).with_bitset(num_bytes, false);
+            for i in 0..array.len() {
+                if array.is_valid(i) {
+                    bit_util::set_bit(nulls.data_mut(), i);
+                }
+            }
+            nulls.freeze()
+        }
+    })
+}
+
+/// Returns the boolean that every valid value of `from` casts to, if they all cast to
+/// the same one, so that the output bitmap can be filled at once. Null slots are
+/// skipped, and the scan stops at the first value that differs.
+fn numeric_to_bool_constant_hint<T, F>(
+    from: &PrimitiveArray<T>,
+    is_true: &F,
+) -> Option<bool>
+where
+    T: ArrowPrimitiveType + ArrowNumericType,
+    F: Fn(T::Native) -> bool,
+{
+    let mut valid = (0..from.len())
+        .filter(|&i| from.is_valid(i))
+        .map(|i| is_true(from.value(i)));
+    let first = valid.next()?;
+    if valid.all(|v| v == first) {
+        Some(first)
+    } else {
+        None
+    }
+}
+
+/// Tests values with `is_true` 64 at a time, writing each result straight into a word
+/// of the output bitmap
+///
+/// With `constant_hint`, `from` is first checked for a single value with
+/// `numeric_to_bool_constant_hint`.
+fn numeric_to_bool_cast<T, F>(
+    from: &PrimitiveArray<T>,
+    constant_hint: bool,
+    is_true: F,
+) -> Result<BooleanArray>
+where
+    T: ArrowPrimitiveType + ArrowNumericType,
+    F: Fn(T::Native) -> bool,
+{
+    let num_bytes = bit_util::ceil(from.len(), 8);
+    let constant = if constant_hint {
+        numeric_to_bool_constant_hint(from, &is_true)
+    } else {
+        None
+    };
+    if let Some(value) = constant {
+        let values = MutableBuffer::new(num_bytes).with_bitset(num_bytes, value);
+        let data = ArrayData::new(
+            DataType::Boolean,
+            from.len(),
+            Some(from.null_count()),
+            null_bitmap_from_start(from),
+            0,
+            vec![values.freeze()],
+            vec![],
+        );
+        return Ok(BooleanArray::from(Arc::new(data)));
+    }
+    let mut values = MutableBuffer::new(num_bytes).with_bitset(num_bytes, false);
+    {
+        let bytes = values.data_mut();
+        for (i, chunk) in from.value_slice(0, from.len()).chunks(64).enumerate() {
+            let mut word = 0u64;
+            for (bit, value) in chunk.iter().enumerate() {
+                if is_true(*value) {
+                    word |= 1 << bit;
+                }
+            }
+            let start = i * 8;
+            let end = num_bytes.min(start + 8);
+            bytes[start..end].copy_from_slice(&word.to_le_bytes()[..end - start]);
+        }
+    }
+
+    // the output starts at offset 0, so the null bitmap has to as well
+    let data = ArrayData::new(
+        DataType::Boolean,
+        from.len(),
+        Some(from.null_count()),
+        null_bitmap_from_start(from),
+        0,
+        vec![values.freeze()],
+        vec![],
+    );
+    Ok(BooleanArray::from(Arc::new(data)))
+}
+
+/// Cast Utf8 to Boolean, using the keywords of `options` if set
+///
+/// Unrecognized values are null, or fail the cast with `OverflowMode::Error`.
+fn cast_utf8_to_bool(array: &ArrayRef, options: &CastOptions) -> Result<ArrayRef> {
+    let keywords_or = |keywords: &Option<Vec<String>>, defaults: &[&str]| match keywords {
+        Some(keywords) => keywords.iter().map(|k| k.to_lowercase()).collect(),
+        None => defaults.iter().map(|k| k.to_string()).collect::<Vec<_>>(),
+    };
+    let true_keywords = keywords_or(&options.bool_true_keywords, &["true", "t", "1"]);
+    let false_keywords = keywords_or(&options.bool_false_keywords, &["false", "f", "0"]);
+
+    let from = array.as_any().downcast_ref::<BinaryArray>().unwrap();
+    let mut b = BooleanBuilder::new(from.len());
+
+    for i in 0..from.len() {
+        if from.is_null(i) {
+            b.append_null()?;
+            continue;
+        }
+        // values that aren't valid UTF-8 match no keyword
+        let value = utf8_value(from, i).map(|value| {
+            if options.strip_quotes {
+                strip_quotes(value).to_lowercase()
+            } else {
+                value.to_lowercase()
+            }
+        });
+        let is_keyword = |keywords: &Vec<String>| match value {
+            Some(ref value) => keywords.contains(value),
+            None => false,
+        };
+        if is_keyword(&true_keywords) {
+            b.append_value(true)?;
+        } else if is_keyword(&false_keywords) {
+            b.append_value(false)?;
+        } else if options.overflow == OverflowMode::Error {
+            return Err(ArrowError::ComputeError(format!(
+                "Value {:?} at index {} is not a valid Boolean",
+                String::from_utf8_lossy(from.value(i)),
+                i
+            )));
+        } else {
+            b.append_null()?;
+        }
+    }
+
+    Ok(Arc::new(b.finish()) as ArrayRef)
+}
+
+/// Cast Boolean types to numeric
+///
+/// `false` returns 0 while `true` returns 1
+fn cast_bool_to_numeric<TO>(from: &ArrayRef) -> Result<ArrayRef>
+where
+    TO: ArrowNumericType,
+    TO::Native: num::cast::NumCast,
+{
+    if from.null_count() == from.len() {
+        return Ok(null_primitive_array::<TO>(from.len()));
+    }
+    match bool_to_numeric_cast::<TO>(
+        from.as_any().downcast_ref::<BooleanArray>().unwrap(),
+    ) {
+        Ok(to) => Ok(Arc::new(to) as ArrayRef),
+        Err(e) => Err(e),
+    }
+}
+
+lazy_static! {
+    /// The 8 bytes, each 0 or 1, that a byte of a boolean bitmap expands to
+    static ref BOOL_TO_BYTES: Vec<[u8; 8]> = (0..256)
+        .map(|byte| {
+            let mut bytes = [0u8; 8];
+            for (bit, b) in bytes.iter_mut().enumerate() {
+                *b = ((byte >> bit) & 1) as u8;
+            }
+            bytes
+        })
+        .collect();
+}
+
+/// Cast Boolean to an 8-bit integer type, expanding whole bytes of the bitmap at once
+///
+/// Arrays that don't start on a byte boundary are cast value by value.
+fn cast_bool_to_byte<TO>(from: &ArrayRef) -> Result<ArrayRef>
+where
+    TO: ArrowNumericType,
+    TO::Native: num::cast::NumCast,
+{
+    if from.offset() % 8 != 0 {
+        return cast_bool_to_numeric::<TO>(from);
+    }
+    let data = from.data_ref();
+    let byte_offset = from.offset() / 8;
+    let bits = &data.buffers()[0].data()[byte_offset..];
+
+    let mut values = Vec::with_capacity(from.len() + 8);
+    for byte in bits.iter().take((from.len() + 7) / 8) {
+        values.extend_from_slice(&BOOL_TO_BYTES[*byte as usize]);
+    }
+    values.truncate(from.len());
+
+    let array_data = ArrayData::new(
+        TO::get_data_type(),
+        from.len(),
+        Some(from.null_count()),
+        data.null_bitmap()
+            .as_ref()
+            .map(|bitmap| bitmap.bits.slice(byte_offset)),
+        0,
+        vec![Buffer::from(values)],
+        vec![],
+    );
+    Ok(make_array(Arc::new(array_data)))
+}
+
+fn bool_to_numeric_cast<T>(from: &BooleanArray) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: num::NumCast,
+{
+    let mut b = PrimitiveBuilder::<T>::new(from.len());
+
+    for i in 0..from.len() {
+        if from.is_null(i) {
+            b.append_null()?;
+        } else {
+            if from.value(i) {
+                // a workaround to cast a primitive to T::Native, infallible
+                match num::cast::cast(1) {
+                    Some(v) => b.append_value(v)?,
+                    None => b.append_null()?,
+                };
             } else {
                 b.append_value(T::default_value())?;
             }
         }
     }
 
-    Ok(b.finish())
-}
+    Ok(b.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::Buffer;
+
+    #[test]
+    fn test_cast_i32_to_f64() {
+        let a = Int32Array::from(vec![5, 6, 7, 8, 9]);
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::Float64).unwrap();
+        let c = b.as_any().downcast_ref::<Float64Array>().unwrap();
+        assert_eq!(5.0, c.value(0));
+        assert_eq!(6.0, c.value(1));
+        assert_eq!(7.0, c.value(2));
+        assert_eq!(8.0, c.value(3));
+        assert_eq!(9.0, c.value(4));
+    }
+
+    #[test]
+    fn test_cast_i32_to_u8() {
+        let a = Int32Array::from(vec![-5, 6, -7, 8, 100000000]);
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::UInt8).unwrap();
+        let c = b.as_any().downcast_ref::<UInt8Array>().unwrap();
+        assert_eq!(false, c.is_valid(0));
+        assert_eq!(6, c.value(1));
+        assert_eq!(false, c.is_valid(2));
+        assert_eq!(8, c.value(3));
+        // overflows return None
+        assert_eq!(false, c.is_valid(4));
+    }
+
+    #[test]
+    fn test_cast_i32_to_u8_sliced() {
+        let a = Int32Array::from(vec![-5, 6, -7, 8, 100000000]);
+        let array = Arc::new(a) as ArrayRef;
+        assert_eq!(0, array.offset());
+        let array = array.slice(2, 3);
+        assert_eq!(2, array.offset());
+        let b = cast(&array, &DataType::UInt8).unwrap();
+        assert_eq!(3, b.len());
+        assert_eq!(0, b.offset());
+        let c = b.as_any().downcast_ref::<UInt8Array>().unwrap();
+        assert_eq!(false, c.is_valid(0));
+        assert_eq!(8, c.value(1));
+        // overflows return None
+        assert_eq!(false, c.is_valid(2));
+    }
+
+    #[test]
+    fn test_cast_i32_to_i32() {
+        let a = Int32Array::from(vec![5, 6, 7, 8, 9]);
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::Int32).unwrap();
+        let c = b.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(5, c.value(0));
+        assert_eq!(6, c.value(1));
+        assert_eq!(7, c.value(2));
+        assert_eq!(8, c.value(3));
+        assert_eq!(9, c.value(4));
+    }
+
+    #[test]
+    fn test_cast_i32_to_list_i32() {
+        let a = Int32Array::from(vec![5, 6, 7, 8, 9]);
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::List(Box::new(DataType::Int32))).unwrap();
+        assert_eq!(5, b.len());
+        let arr = b.as_any().downcast_ref::<ListArray>().unwrap();
+        assert_eq!(0, arr.value_offset(0));
+        assert_eq!(1, arr.value_offset(1));
+        assert_eq!(2, arr.value_offset(2));
+        assert_eq!(3, arr.value_offset(3));
+        assert_eq!(4, arr.value_offset(4));
+        assert_eq!(1, arr.value_length(0));
+        assert_eq!(1, arr.value_length(1));
+        assert_eq!(1, arr.value_length(2));
+        assert_eq!(1, arr.value_length(3));
+        assert_eq!(1, arr.value_length(4));
+        let values = arr.values();
+        let c = values.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(5, c.value(0));
+        assert_eq!(6, c.value(1));
+        assert_eq!(7, c.value(2));
+        assert_eq!(8, c.value(3));
+        assert_eq!(9, c.value(4));
+    }
+
+    #[test]
+    fn test_cast_i32_to_list_i32_nullable() {
+        let a = Int32Array::from(vec![Some(5), None, Some(7), Some(8), Some(9)]);
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::List(Box::new(DataType::Int32))).unwrap();
+        assert_eq!(5, b.len());
+        assert_eq!(1, b.null_count());
+        let arr = b.as_any().downcast_ref::<ListArray>().unwrap();
+        assert_eq!(0, arr.value_offset(0));
+        assert_eq!(1, arr.value_offset(1));
+        assert_eq!(2, arr.value_offset(2));
+        assert_eq!(3, arr.value_offset(3));
+        assert_eq!(4, arr.value_offset(4));
+        assert_eq!(1, arr.value_length(0));
+        assert_eq!(1, arr.value_length(1));
+        assert_eq!(1, arr.value_length(2));
+        assert_eq!(1, arr.value_length(3));
+        assert_eq!(1, arr.value_length(4));
+        let values = arr.values();
+        let c = values.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(1, c.null_count());
+        assert_eq!(5, c.value(0));
+        assert_eq!(false, c.is_valid(1));
+        assert_eq!(7, c.value(2));
+        assert_eq!(8, c.value(3));
+        assert_eq!(9, c.value(4));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Cast kernel does not yet support sliced (non-zero offset) arrays"
+    )]
+    fn test_cast_i32_to_list_i32_nullable_sliced() {
+        let a = Int32Array::from(vec![Some(5), None, Some(7), Some(8), None]);
+        let array = Arc::new(a) as ArrayRef;
+        let array = array.slice(2, 3);
+        let b = cast(&array, &DataType::List(Box::new(DataType::Int32))).unwrap();
+        assert_eq!(3, b.len());
+        assert_eq!(1, b.null_count());
+        let arr = b.as_any().downcast_ref::<ListArray>().unwrap();
+        assert_eq!(0, arr.value_offset(0));
+        assert_eq!(1, arr.value_offset(1));
+        assert_eq!(2, arr.value_offset(2));
+        assert_eq!(1, arr.value_length(0));
+        assert_eq!(1, arr.value_length(1));
+        assert_eq!(1, arr.value_length(2));
+        let values = arr.values();
+        let c = values.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(1, c.null_count());
+        assert_eq!(7, c.value(0));
+        assert_eq!(8, c.value(1));
+        // if one removes the non-zero-offset limitation, this assertion passes when it
+        // shouldn't
+        assert_eq!(0, c.value(2));
+    }
+
+    #[test]
+    fn test_cast_utf_to_i32() {
+        let a = BinaryArray::from(vec!["5", "6", "seven", "8", "9.1"]);
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::Int32).unwrap();
+        let c = b.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(5, c.value(0));
+        assert_eq!(6, c.value(1));
+        assert_eq!(false, c.is_valid(2));
+        assert_eq!(8, c.value(3));
+        assert_eq!(false, c.is_valid(2));
+    }
+
+    #[test]
+    fn test_cast_with_reasons() {
+        let a = BinaryArray::from(vec!["5", "x"]);
+        let array = Arc::new(a) as ArrayRef;
+        let (b, reasons) = cast_with_reasons(&array, &DataType::Int32).unwrap();
+        let c = b.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(5, c.value(0));
+        assert_eq!(false, c.is_valid(1));
+        assert_eq!(2, reasons.len());
+        assert_eq!(false, reasons.is_valid(0));
+        assert_eq!("parse", reasons.get_string(1));
+
+        let a =
+            Float64Array::from(vec![Some(1.0), None, Some(1e20), Some(std::f64::NAN)]);
+        let array = Arc::new(a) as ArrayRef;
+        let (_, reasons) = cast_with_reasons(&array, &DataType::Int32).unwrap();
+        assert_eq!(false, reasons.is_valid(0));
+        assert_eq!(false, reasons.is_valid(1));
+        assert_eq!("overflow", reasons.get_string(2));
+        assert_eq!("non-finite", reasons.get_string(3));
+    }
+
+    #[test]
+    fn test_cast_with_reasons_no_new_nulls() {
+        let a = Int32Array::from(vec![Some(1), None, Some(3)]);
+        let array = Arc::new(a) as ArrayRef;
+        let (b, reasons) = cast_with_reasons(&array, &DataType::Int64).unwrap();
+        assert_eq!(3, b.len());
+        assert_eq!(0, reasons.len());
+    }
+
+    #[test]
+    fn test_cast_utf8_to_utf8_pad() {
+        let array = utf8_array(vec![Some("ab"), None, Some("abcdefg")]);
+        let options = CastOptions {
+            pad: Some((5, ' ', PadSide::Right)),
+            ..Default::default()
+        };
+        let b = cast_with_options(&array, &DataType::Utf8, &options).unwrap();
+        let c = b.as_any().downcast_ref::<BinaryArray>().unwrap();
+        assert_eq!("ab   ", c.get_string(0));
+        assert_eq!(false, c.is_valid(1));
+        // longer values are left untouched
+        assert_eq!("abcdefg", c.get_string(2));
+
+        let options = CastOptions {
+            pad: Some((4, '*', PadSide::Left)),
+            ..Default::default()
+        };
+        let b = cast_with_options(&array, &DataType::Utf8, &options).unwrap();
+        let c = b.as_any().downcast_ref::<BinaryArray>().unwrap();
+        assert_eq!("**ab", c.get_string(0));
+    }
+
+    #[test]
+    fn test_cast_utf8_to_utf8_strip() {
+        let array = utf8_array(vec![Some("urn:abc"), Some("xyz"), None]);
+        let options = CastOptions {
+            strip_prefix: Some("urn:".to_string()),
+            ..Default::default()
+        };
+        let b = cast_with_options(&array, &DataType::Utf8, &options).unwrap();
+        let c = b.as_any().downcast_ref::<BinaryArray>().unwrap();
+        assert_eq!("abc", c.get_string(0));
+        assert_eq!("xyz", c.get_string(1));
+        assert_eq!(false, c.is_valid(2));
+
+        let array = utf8_array(vec![Some("key.tmp"), Some("key"), Some(".tmp")]);
+        let options = CastOptions {
+            strip_suffix: Some(".tmp".to_string()),
+            ..Default::default()
+        };
+        let b = cast_with_options(&array, &DataType::Utf8, &options).unwrap();
+        let c = b.as_any().downcast_ref::<BinaryArray>().unwrap();
+        assert_eq!("key", c.get_string(0));
+        assert_eq!("key", c.get_string(1));
+        assert_eq!("", c.get_string(2));
+    }
+
+    #[test]
+    fn test_cast_f32_to_i32_bitcast() {
+        let a = Float32Array::from(vec![Some(1.0), None, Some(-2.5)]);
+        let array = Arc::new(a) as ArrayRef;
+        let options = CastOptions {
+            bitcast: true,
+            ..Default::default()
+        };
+        let b = cast_with_options(&array, &DataType::Int32, &options).unwrap();
+        let c = b.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(0x3F80_0000, c.value(0));
+        assert_eq!(false, c.is_valid(1));
+        assert_eq!((-2.5f32).to_bits() as i32, c.value(2));
+        // the value buffer is reused
+        assert_eq!(
+            array.data().buffers()[0].raw_data(),
+            b.data().buffers()[0].raw_data()
+        );
+
+        // and casting back restores the original values
+        let d = cast_with_options(&b, &DataType::Float32, &options).unwrap();
+        let e = d.as_any().downcast_ref::<Float32Array>().unwrap();
+        assert_eq!(1.0, e.value(0));
+        assert_eq!(-2.5, e.value(2));
+
+        // the default is a value conversion
+        let b = cast(&array, &DataType::Int32).unwrap();
+        let c = b.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(1, c.value(0));
+    }
+
+    #[test]
+    fn test_cast_int_to_utf8_radix() {
+        let a = UInt64Array::from(vec![Some(255), None, Some(10), Some(std::u64::MAX)]);
+        let array = Arc::new(a) as ArrayRef;
+        let options = CastOptions {
+            int_radix: Some(16),
+            ..Default::default()
+        };
+        let b = cast_with_options(&array, &DataType::Utf8, &options).unwrap();
+        let c = b.as_any().downcast_ref::<BinaryArray>().unwrap();
+        assert_eq!("ff", c.get_string(0));
+        assert_eq!(false, c.is_valid(1));
+        assert_eq!("a", c.get_string(2));
+        assert_eq!("ffffffffffffffff", c.get_string(3));
+
+        let options = CastOptions {
+            int_radix: Some(2),
+            ..Default::default()
+        };
+        let b = cast_with_options(&array, &DataType::Utf8, &options).unwrap();
+        let c = b.as_any().downcast_ref::<BinaryArray>().unwrap();
+        assert_eq!("1010", c.get_string(2));
+
+        let a = Int64Array::from(vec![-255, 0, std::i64::MIN]);
+        let array = Arc::new(a) as ArrayRef;
+        let options = CastOptions {
+            int_radix: Some(16),
+            ..Default::default()
+        };
+        let b = cast_with_options(&array, &DataType::Utf8, &options).unwrap();
+        let c = b.as_any().downcast_ref::<BinaryArray>().unwrap();
+        assert_eq!("-ff", c.get_string(0));
+        assert_eq!("0", c.get_string(1));
+        assert_eq!("-8000000000000000", c.get_string(2));
+    }
+
+    #[test]
+    fn test_cast_float_to_utf8_negative_zero() {
+        let a = Float64Array::from(vec![Some(-0.0), Some(0.0), None, Some(-1.5)]);
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::Utf8).unwrap();
+        let c = b.as_any().downcast_ref::<BinaryArray>().unwrap();
+        assert_eq!("-0", c.get_string(0));
+        assert_eq!("0", c.get_string(1));
+
+        let options = CastOptions {
+            normalize_negative_zero: true,
+            ..Default::default()
+        };
+        let b = cast_with_options(&array, &DataType::Utf8, &options).unwrap();
+        let c = b.as_any().downcast_ref::<BinaryArray>().unwrap();
+        assert_eq!("0", c.get_string(0));
+        assert_eq!("0", c.get_string(1));
+        assert!(c.is_null(2));
+        assert_eq!("-1.5", c.get_string(3));
+
+        let a = Float32Array::from(vec![-0.0]);
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast_with_options(&array, &DataType::Utf8, &options).unwrap();
+        let c = b.as_any().downcast_ref::<BinaryArray>().unwrap();
+        assert_eq!("0", c.get_string(0));
+    }
+
+    #[test]
+    fn test_cast_int_to_utf8_zero_pad() {
+        let a = Int32Array::from(vec![Some(42), Some(-42), None, Some(1234567)]);
+        let array = Arc::new(a) as ArrayRef;
+        let options = CastOptions {
+            int_zero_pad: Some(5),
+            ..Default::default()
+        };
+        let b = cast_with_options(&array, &DataType::Utf8, &options).unwrap();
+        let c = b.as_any().downcast_ref::<BinaryArray>().unwrap();
+        assert_eq!("00042", c.get_string(0));
+        assert_eq!("-0042", c.get_string(1));
+        assert!(c.is_null(2));
+        assert_eq!("1234567", c.get_string(3));
+    }
+
+    #[test]
+    fn test_cast_float_to_utf8_percent() {
+        let a = Float64Array::from(vec![Some(0.125), None, Some(-0.5), Some(1.0)]);
+        let array = Arc::new(a) as ArrayRef;
+        let options = CastOptions {
+            format_percent: true,
+            float_precision: Some(1),
+            ..Default::default()
+        };
+        let b = cast_with_options(&array, &DataType::Utf8, &options).unwrap();
+        let c = b.as_any().downcast_ref::<BinaryArray>().unwrap();
+        assert_eq!("12.5%", c.get_string(0));
+        assert!(c.is_null(1));
+        assert_eq!("-50.0%", c.get_string(2));
+        assert_eq!("100.0%", c.get_string(3));
+
+        let options = CastOptions {
+            format_percent: true,
+            ..Default::default()
+        };
+        let b = cast_with_options(&array, &DataType::Utf8, &options).unwrap();
+        let c = b.as_any().downcast_ref::<BinaryArray>().unwrap();
+        assert_eq!("12.5%", c.get_string(0));
+        assert_eq!("100%", c.get_string(3));
+
+        let a = Float32Array::from(vec![0.125, 2.0 / 3.0]);
+        let array = Arc::new(a) as ArrayRef;
+        let options = CastOptions {
+            float_precision: Some(3),
+            ..Default::default()
+        };
+        let b = cast_with_options(&array, &DataType::Utf8, &options).unwrap();
+        let c = b.as_any().downcast_ref::<BinaryArray>().unwrap();
+        assert_eq!("0.125", c.get_string(0));
+        assert_eq!("0.667", c.get_string(1));
+    }
+
+    #[test]
+    fn test_cast_int_to_utf8_group_digits() {
+        let a = Int32Array::from(vec![
+            Some(1234567),
+            Some(-1234),
+            None,
+            Some(123),
+            Some(-100),
+        ]);
+        let array = Arc::new(a) as ArrayRef;
+        let options = CastOptions {
+            group_digits: Some(','),
+            ..Default::default()
+        };
+        let b = cast_with_options(&array, &DataType::Utf8, &options).unwrap();
+        let c = b.as_any().downcast_ref::<BinaryArray>().unwrap();
+        assert_eq!("1,234,567", c.get_string(0));
+        assert_eq!("-1,234", c.get_string(1));
+        assert!(c.is_null(2));
+        assert_eq!("123", c.get_string(3));
+        assert_eq!("-100", c.get_string(4));
+    }
+
+    #[test]
+    #[should_panic(expected = "Integer radix must be between 2 and 36, got 37")]
+    fn test_cast_int_to_utf8_invalid_radix() {
+        let array = Arc::new(Int32Array::from(vec![1])) as ArrayRef;
+        let options = CastOptions {
+            int_radix: Some(37),
+            ..Default::default()
+        };
+        cast_with_options(&array, &DataType::Utf8, &options).unwrap();
+    }
+
+    #[test]
+    fn test_cast_i64_to_i16_saturate() {
+        let a = Int64Array::from(vec![
+            Some(std::i64::MIN),
+            Some(-40000),
+            Some(-5),
+            None,
+            Some(40000),
+            Some(std::i64::MAX),
+        ]);
+        let array = Arc::new(a) as ArrayRef;
+        let options = CastOptions {
+            overflow: OverflowMode::Saturate,
+            ..Default::default()
+        };
+        let b = cast_with_options(&array, &DataType::Int16, &options).unwrap();
+        let c = b.as_any().downcast_ref::<Int16Array>().unwrap();
+        assert_eq!(std::i16::MIN, c.value(0));
+        assert_eq!(std::i16::MIN, c.value(1));
+        assert_eq!(-5, c.value(2));
+        assert_eq!(false, c.is_valid(3));
+        assert_eq!(std::i16::MAX, c.value(4));
+        assert_eq!(std::i16::MAX, c.value(5));
+    }
+
+    #[test]
+    fn test_cast_f64_to_u8_saturate() {
+        let a = Float64Array::from(vec![-1.5, 12.7, 300.0, std::f64::NAN]);
+        let array = Arc::new(a) as ArrayRef;
+        let options = CastOptions {
+            overflow: OverflowMode::Saturate,
+            ..Default::default()
+        };
+        let b = cast_with_options(&array, &DataType::UInt8, &options).unwrap();
+        let c = b.as_any().downcast_ref::<UInt8Array>().unwrap();
+        assert_eq!(0, c.value(0));
+        assert_eq!(12, c.value(1));
+        assert_eq!(255, c.value(2));
+        assert_eq!(false, c.is_valid(3));
+    }
+
+    #[test]
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    fn test_simd_clamp_matches_scalar_clamp() {
+        let values: Vec<Option<i64>> = (0..50_000i64)
+            .map(|i| {
+                if i % 7 == 0 {
+                    None
+                } else {
+                    Some((i - 25_000) * (i % 13) * 3)
+                }
+            })
+            .collect();
+        let a = Int64Array::from(values);
+        let lower = std::i16::MIN as i64;
+        let upper = std::i16::MAX as i64;
+        assert_eq!(
+            scalar_clamp_values(&a, lower, upper, false),
+            simd_clamp_values(&a, lower, upper, false)
+        );
+
+        // a sliced array has a remainder that is not a full chunk of lanes
+        let sliced = Arc::new(a).slice(3, 49_990);
+        let sliced = sliced.as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(
+            scalar_clamp_values(sliced, lower, upper, false),
+            simd_clamp_values(sliced, lower, upper, false)
+        );
+
+        // infinities are kept in full chunks of lanes and in the remainder
+        let values: Vec<f64> = (0..11)
+            .map(|i| match i % 3 {
+                0 => std::f64::INFINITY,
+                1 => std::f64::NEG_INFINITY,
+                _ => 1e300,
+            })
+            .collect();
+        let a = Float64Array::from(values);
+        let lower = -(std::f32::MAX as f64);
+        let upper = std::f32::MAX as f64;
+        let clamped = simd_clamp_values(&a, lower, upper, true);
+        assert_eq!(scalar_clamp_values(&a, lower, upper, true), clamped);
+        assert_eq!(std::f64::INFINITY, clamped[9]);
+        assert_eq!(std::f64::NEG_INFINITY, clamped[10]);
+        assert_eq!(upper, clamped[8]);
+    }
+
+    #[test]
+    fn test_cast_f64_to_f32_saturate_keeps_infinities() {
+        let a = Float64Array::from(vec![
+            Some(std::f64::INFINITY),
+            Some(std::f64::NEG_INFINITY),
+            Some(1e300),
+            None,
+            Some(1.5),
+        ]);
+        let array = Arc::new(a) as ArrayRef;
+        let options = CastOptions {
+            overflow: OverflowMode::Saturate,
+            ..Default::default()
+        };
+        let b = cast_with_options(&array, &DataType::Float32, &options).unwrap();
+        let c = b.as_any().downcast_ref::<Float32Array>().unwrap();
+        assert_eq!(std::f32::INFINITY, c.value(0));
+        assert_eq!(std::f32::NEG_INFINITY, c.value(1));
+        assert_eq!(std::f32::MAX, c.value(2));
+        assert!(c.is_null(3));
+        assert_eq!(1.5, c.value(4));
+    }
+
+    #[test]
+    fn test_cast_maybe_shared() {
+        let a = Int32Array::from(vec![5, 6, 7]);
+        let array = Arc::new(a) as ArrayRef;
+        let (b, shared) = cast_maybe_shared(&array, &DataType::Int32).unwrap();
+        assert!(shared);
+        assert_eq!(&DataType::Int32, b.data_type());
+
+        let (b, shared) = cast_maybe_shared(&array, &DataType::Float64).unwrap();
+        assert!(!shared);
+        let c = b.as_any().downcast_ref::<Float64Array>().unwrap();
+        assert_eq!(5.0, c.value(0));
+        assert_eq!(7.0, c.value(2));
+    }
+
+    #[test]
+    fn test_cast_utf8_to_f64_decimal_separator() {
+        let array = utf8_array(vec![Some("1.234,56"), Some("-0,5"), Some("7"), None]);
+        let options = CastOptions {
+            decimal_separator: ',',
+            ..Default::default()
+        };
+        let b = cast_with_options(&array, &DataType::Float64, &options).unwrap();
+        let c = b.as_any().downcast_ref::<Float64Array>().unwrap();
+        assert_eq!(1234.56, c.value(0));
+        assert_eq!(-0.5, c.value(1));
+        assert_eq!(7.0, c.value(2));
+        assert_eq!(false, c.is_valid(3));
+    }
+
+    #[test]
+    fn test_cast_bool_to_i8_lookup_table() {
+        let values: Vec<Option<bool>> = (0..4096)
+            .map(|i| if i % 5 == 0 { None } else { Some(i % 3 == 0) })
+            .collect();
+        let array = Arc::new(BooleanArray::from(values)) as ArrayRef;
+
+        // a byte aligned slice takes the lookup table path, others don't
+        for &(offset, len) in &[(0, 4096), (8, 4000), (3, 4090)] {
+            let sliced = array.slice(offset, len);
+            let from = sliced.as_any().downcast_ref::<BooleanArray>().unwrap();
+            let expected = bool_to_numeric_cast::<Int8Type>(from).unwrap();
+            let b = cast(&sliced, &DataType::Int8).unwrap();
+            let c = b.as_any().downcast_ref::<Int8Array>().unwrap();
+            assert_eq!(expected.len(), c.len());
+            assert_eq!(expected.null_count(), c.null_count());
+            for i in 0..c.len() {
+                assert_eq!(expected.is_null(i), c.is_null(i));
+                if c.is_valid(i) {
+                    assert_eq!(expected.value(i), c.value(i));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_cast_date32_epoch_shift() {
+        let a = Date32Array::from(vec![Some(0), Some(-365), None, Some(std::i32::MAX)]);
+        let array = Arc::new(a) as ArrayRef;
+        let options = CastOptions {
+            date_epoch_shift_days: 365,
+            ..Default::default()
+        };
+        let b = cast_with_options(&array, &DataType::Date32(DateUnit::Day), &options)
+            .unwrap();
+        let c = b.as_any().downcast_ref::<Date32Array>().unwrap();
+        assert_eq!(365, c.value(0));
+        assert_eq!(0, c.value(1));
+        assert!(c.is_null(2));
+        assert!(c.is_null(3));
+    }
+
+    #[test]
+    fn test_cast_date64_epoch_shift() {
+        let a = Date64Array::from(vec![86_400_000, 0]);
+        let array = Arc::new(a) as ArrayRef;
+        let options = CastOptions {
+            date_epoch_shift_days: -1,
+            ..Default::default()
+        };
+        let b =
+            cast_with_options(&array, &DataType::Date64(DateUnit::Millisecond), &options)
+                .unwrap();
+        let c = b.as_any().downcast_ref::<Date64Array>().unwrap();
+        assert_eq!(0, c.value(0));
+        assert_eq!(-86_400_000, c.value(1));
+    }
+
+    #[test]
+    fn test_cast_checked_i32_to_i8() {
+        let a = Int32Array::from(vec![Some(1), Some(200), None, Some(-129), Some(-128)]);
+        let (b, overflows) = cast_checked::<Int32Type, Int8Type>(&a).unwrap();
+        assert_eq!(vec![1, 3], overflows);
+        assert_eq!(1, b.value(0));
+        assert!(b.is_null(1));
+        assert!(b.is_null(2));
+        assert!(b.is_null(3));
+        assert_eq!(-128, b.value(4));
+    }
+
+    #[test]
+    fn test_cast_saturating_with_flags_i64_to_i16() {
+        let a = Int64Array::from(vec![
+            Some(1),
+            Some(40_000),
+            None,
+            Some(-40_000),
+            Some(-32_768),
+            Some(32_767),
+        ]);
+        let (b, clamped) =
+            cast_saturating_with_flags::<Int64Type, Int16Type>(&a).unwrap();
+        assert_eq!(1, b.value(0));
+        assert_eq!(32_767, b.value(1));
+        assert!(b.is_null(2));
+        assert_eq!(-32_768, b.value(3));
+        assert_eq!(-32_768, b.value(4));
+        assert_eq!(32_767, b.value(5));
+
+        assert_eq!(0, clamped.null_count());
+        let flags: Vec<bool> = (0..clamped.len()).map(|i| clamped.value(i)).collect();
+        assert_eq!(vec![false, true, false, true, false, false], flags);
+    }
+
+    #[test]
+    fn test_cast_wrapping_with_flags_i32_to_u8() {
+        let a = Int32Array::from(vec![Some(300), Some(255), None, Some(-1)]);
+        let (b, wrapped) = cast_wrapping_with_flags::<Int32Type, UInt8Type>(&a).unwrap();
+        assert_eq!(44, b.value(0));
+        assert_eq!(255, b.value(1));
+        assert!(b.is_null(2));
+        assert_eq!(255, b.value(3));
+
+        assert_eq!(0, wrapped.null_count());
+        let flags: Vec<bool> = (0..wrapped.len()).map(|i| wrapped.value(i)).collect();
+        assert_eq!(vec![true, false, false, true], flags);
+    }
+
+    #[test]
+    fn test_cast_affine_celsius_to_fahrenheit() {
+        let a = Int32Array::from(vec![Some(0), Some(100), None, Some(-40), Some(37)]);
+        let b =
+            cast_affine::<Int32Type, Int32Type>(&a, 1.8, 32.0, &CastOptions::default())
+                .unwrap();
+        assert_eq!(32, b.value(0));
+        assert_eq!(212, b.value(1));
+        assert!(b.is_null(2));
+        assert_eq!(-40, b.value(3));
+        // 98.6 is truncated by default
+        assert_eq!(98, b.value(4));
+
+        let options = CastOptions {
+            float_to_int_rounding: FloatToIntRounding::Round,
+            ..CastOptions::default()
+        };
+        let b = cast_affine::<Int32Type, Int32Type>(&a, 1.8, 32.0, &options).unwrap();
+        assert_eq!(99, b.value(4));
+    }
+
+    #[test]
+    fn test_cast_i32_to_bool_bitmap() {
+        let values: Vec<Option<i32>> = (0..100_000)
+            .map(|i| if i % 7 == 0 { None } else { Some(i % 3) })
+            .collect();
+        let array = Arc::new(Int32Array::from(values)) as ArrayRef;
+
+        for &(offset, len) in &[(0, 100_000), (64, 99_900), (5, 99_990)] {
+            let sliced = array.slice(offset, len);
+            let b = cast(&sliced, &DataType::Boolean).unwrap();
+            let c = b.as_any().downcast_ref::<BooleanArray>().unwrap();
+
+            // compare with casting one value at a time
+            let from = sliced.as_any().downcast_ref::<Int32Array>().unwrap();
+            assert_eq!(from.len(), c.len());
+            assert_eq!(from.null_count(), c.null_count());
+            for i in 0..len {
+                assert_eq!(from.is_valid(i), c.is_valid(i));
+                if c.is_valid(i) {
+                    assert_eq!(from.value(i) != 0, c.value(i));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_cast_bool_to_date32_not_supported() {
+        let array = Arc::new(BooleanArray::from(vec![true, false])) as ArrayRef;
+        let to_type = DataType::Date32(DateUnit::Day);
+        assert_eq!(
+            ArrowError::CastNotSupported {
+                from: DataType::Boolean,
+                to: to_type.clone(),
+            },
+            cast(&array, &to_type).err().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_cast_utf8_to_f64_percent() {
+        let array = utf8_array(vec![Some("12.5%"), Some("12.5"), Some("%"), None]);
+        let options = CastOptions {
+            parse_percent: true,
+            ..Default::default()
+        };
+        let b = cast_with_options(&array, &DataType::Float64, &options).unwrap();
+        let c = b.as_any().downcast_ref::<Float64Array>().unwrap();
+        assert_eq!(0.125, c.value(0));
+        assert_eq!(12.5, c.value(1));
+        assert!(c.is_null(2));
+        assert!(c.is_null(3));
+
+        let b = cast_with_options(&array, &DataType::Int32, &options).unwrap();
+        assert!(b.is_null(0));
+    }
+
+    #[test]
+    fn test_cast_i32_to_i8_overflow_error() {
+        let a = Int32Array::from(vec![Some(1), None, Some(300)]);
+        let array = Arc::new(a) as ArrayRef;
+        let options = CastOptions {
+            overflow: OverflowMode::Error,
+            ..Default::default()
+        };
+        assert_eq!(
+            ArrowError::ComputeError(
+                "Value 300 at index 2 does not fit into Int8".to_string()
+            ),
+            cast_with_options(&array, &DataType::Int8, &options)
+                .err()
+                .unwrap()
+        );
+        let b = cast_with_options(&array, &DataType::Int64, &options).unwrap();
+        assert_eq!(1, b.null_count());
+    }
+
+    #[test]
+    fn test_cast_timestamp_second_to_millisecond() {
+        let a = TimestampSecondArray::from(vec![Some(1), None, Some(-2)]);
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::Timestamp(TimeUnit::Millisecond)).unwrap();
+        let c = b
+            .as_any()
+            .downcast_ref::<TimestampMillisecondArray>()
+            .unwrap();
+        assert_eq!(1000, c.value(0));
+        assert!(c.is_null(1));
+        assert_eq!(-2000, c.value(2));
+
+        let b = cast(&b, &DataType::Timestamp(TimeUnit::Second)).unwrap();
+        let c = b.as_any().downcast_ref::<TimestampSecondArray>().unwrap();
+        assert_eq!(1, c.value(0));
+        assert_eq!(-2, c.value(2));
+    }
+
+    #[test]
+    fn test_cast_timestamp_nanosecond_to_second_rounding() {
+        let a = TimestampNanosecondArray::from(vec![
+            Some(1_600_000_000),
+            Some(-1_600_000_000),
+            Some(2_500_000_000),
+            None,
+        ]);
+        let array = Arc::new(a) as ArrayRef;
+        let to_type = DataType::Timestamp(TimeUnit::Second);
+        let cast_rounded = |rounding| {
+            let options = CastOptions {
+                float_to_int_rounding: rounding,
+                ..Default::default()
+            };
+            let b = cast_with_options(&array, &to_type, &options).unwrap();
+            let c = b.as_any().downcast_ref::<TimestampSecondArray>().unwrap();
+            assert!(c.is_null(3));
+            (0..3).map(|i| c.value(i)).collect::<Vec<_>>()
+        };
+
+        assert_eq!(vec![1, -1, 2], cast_rounded(FloatToIntRounding::Truncate));
+        assert_eq!(vec![2, -2, 2], cast_rounded(FloatToIntRounding::Round));
+        assert_eq!(vec![1, -2, 2], cast_rounded(FloatToIntRounding::Floor));
+        assert_eq!(vec![2, -1, 3], cast_rounded(FloatToIntRounding::Ceil));
+    }
+
+    #[test]
+    fn test_cast_timestamp_second_to_nanosecond_overflow() {
+        let a = TimestampSecondArray::from(vec![std::i64::MAX - 1, 5]);
+        let array = Arc::new(a) as ArrayRef;
+        let to_type = DataType::Timestamp(TimeUnit::Nanosecond);
+
+        let b = cast(&array, &to_type).unwrap();
+        let c = b
+            .as_any()
+            .downcast_ref::<TimestampNanosecondArray>()
+            .unwrap();
+        assert!(c.is_null(0));
+        assert_eq!(5_000_000_000, c.value(1));
+
+        let options = CastOptions {
+            overflow: OverflowMode::Saturate,
+            ..Default::default()
+        };
+        let b = cast_with_options(&array, &to_type, &options).unwrap();
+        let c = b
+            .as_any()
+            .downcast_ref::<TimestampNanosecondArray>()
+            .unwrap();
+        assert_eq!(std::i64::MAX, c.value(0));
+        assert_eq!(5_000_000_000, c.value(1));
+
+        let options = CastOptions {
+            overflow: OverflowMode::Error,
+            ..Default::default()
+        };
+        assert_eq!(
+            ArrowError::ComputeError(
+                "Value 9223372036854775806 at index 0 does not fit into \
+                 Timestamp(Nanosecond)"
+                    .to_string()
+            ),
+            cast_with_options(&array, &to_type, &options).err().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_cast_i32_to_time32() {
+        let a = Int32Array::from(vec![Some(3_600), Some(-1), Some(86_400), None]);
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::Time32(TimeUnit::Second)).unwrap();
+        let c = b.as_any().downcast_ref::<Time32SecondArray>().unwrap();
+        assert_eq!(3_600, c.value(0));
+        assert!(c.is_null(1));
+        assert!(c.is_null(2));
+        assert!(c.is_null(3));
+
+        let b = cast(&array, &DataType::Time32(TimeUnit::Millisecond)).unwrap();
+        let c = b.as_any().downcast_ref::<Time32MillisecondArray>().unwrap();
+        assert_eq!(3_600, c.value(0));
+        assert_eq!(86_400, c.value(2));
+    }
+
+    #[test]
+    fn test_cast_i64_to_time64_overflow_error() {
+        let a = Int64Array::from(vec![86_400_000_000, 86_399_999_999_999]);
+        let array = Arc::new(a) as ArrayRef;
+        let options = CastOptions {
+            overflow: OverflowMode::Error,
+            ..Default::default()
+        };
+        let to_type = DataType::Time64(TimeUnit::Microsecond);
+        assert_eq!(
+            ArrowError::ComputeError(
+                "Value 86400000000 at index 0 does not fit into Time64(Microsecond)"
+                    .to_string()
+            ),
+            cast_with_options(&array, &to_type, &options).err().unwrap()
+        );
+        let to_type = DataType::Time64(TimeUnit::Nanosecond);
+        let b = cast_with_options(&array, &to_type, &options).unwrap();
+        let c = b.as_any().downcast_ref::<Time64NanosecondArray>().unwrap();
+        assert_eq!(86_400_000_000, c.value(0));
+    }
+
+    #[test]
+    fn test_cast_utf8_to_bool() {
+        let array =
+            utf8_array(vec![Some("True"), Some("f"), Some("1"), Some("yes"), None]);
+        let b = cast(&array, &DataType::Boolean).unwrap();
+        let c = b.as_any().downcast_ref::<BooleanArray>().unwrap();
+        assert_eq!(true, c.value(0));
+        assert_eq!(false, c.value(1));
+        assert_eq!(true, c.value(2));
+        assert!(c.is_null(3));
+        assert!(c.is_null(4));
+    }
+
+    #[test]
+    fn test_cast_utf8_to_bool_keywords() {
+        let array = utf8_array(vec![
+            Some("vrai"),
+            Some("FAUX"),
+            Some("maybe"),
+            Some("true"),
+        ]);
+        let options = CastOptions {
+            bool_true_keywords: Some(vec!["Vrai".to_string()]),
+            bool_false_keywords: Some(vec!["faux".to_string()]),
+            ..Default::default()
+        };
+        let b = cast_with_options(&array, &DataType::Boolean, &options).unwrap();
+        let c = b.as_any().downcast_ref::<BooleanArray>().unwrap();
+        assert_eq!(true, c.value(0));
+        assert_eq!(false, c.value(1));
+        assert!(c.is_null(2));
+        assert!(c.is_null(3));
+    }
+
+    #[test]
+    fn test_cast_utf8_to_bool_error_mode() {
+        let options = CastOptions {
+            overflow: OverflowMode::Error,
+            ..Default::default()
+        };
+        let array = utf8_array(vec![Some("true"), Some("maybe"), Some("no")]);
+        assert_eq!(
+            ArrowError::ComputeError(
+                "Value \"maybe\" at index 1 is not a valid Boolean".to_string()
+            ),
+            cast_with_options(&array, &DataType::Boolean, &options)
+                .err()
+                .unwrap()
+        );
+
+        let array = utf8_array(vec![Some("true"), None, Some("0")]);
+        let b = cast_with_options(&array, &DataType::Boolean, &options).unwrap();
+        let c = b.as_any().downcast_ref::<BooleanArray>().unwrap();
+        assert_eq!(true, c.value(0));
+        assert!(c.is_null(1));
+        assert_eq!(false, c.value(2));
+    }
+
+    #[test]
+    fn test_cast_view_agrees_with_cast() {
+        let int32 = Arc::new(Int32Array::from(vec![Some(1), None])) as ArrayRef;
+        let int64 = Arc::new(Int64Array::from(vec![Some(1), None])) as ArrayRef;
+        let casts = vec![
+            (int32.clone(), DataType::Date32(DateUnit::Day)),
+            (int64.clone(), DataType::Date64(DateUnit::Millisecond)),
+            (int64.clone(), DataType::Timestamp(TimeUnit::Second)),
+            (int32.clone(), DataType::Timestamp(TimeUnit::Second)),
+        ];
+        for (array, to_type) in casts {
+            match cast(&array, &to_type) {
+                Ok(b) => {
+                    let view = cast_view(&array, &to_type).unwrap();
+                    assert_eq!(b.data(), view.materialize().data());
+                    let back = cast(&b, array.data_type()).unwrap();
+                    let view = cast_view(&b, array.data_type()).unwrap();
+                    assert_eq!(back.data(), view.materialize().data());
+                }
+                Err(_) => assert!(cast_view(&array, &to_type).is_err()),
+            }
+        }
+    }
+
+    #[test]
+    fn test_cast_view() {
+        let a = Int64Array::from(vec![Some(1), None, Some(3)]);
+        let array = Arc::new(a) as ArrayRef;
+        let to_type = DataType::Timestamp(TimeUnit::Millisecond);
+        let view = cast_view(&array, &to_type).unwrap();
+        assert_eq!(&to_type, view.data_type());
+        match view {
+            CastView::Reinterpret { ref base, .. } => assert!(Arc::ptr_eq(base, &array)),
+            _ => panic!("expected a reinterpretation"),
+        }
+
+        let b = view.materialize();
+        assert_eq!(
+            array.data().buffers()[0].raw_data(),
+            b.data().buffers()[0].raw_data()
+        );
+        let c = b
+            .as_any()
+            .downcast_ref::<TimestampMillisecondArray>()
+            .unwrap();
+        assert_eq!(1, c.value(0));
+        assert!(c.is_null(1));
+        assert_eq!(3, c.value(2));
+
+        let view = cast_view(&array, &DataType::Float64).unwrap();
+        match view {
+            CastView::Owned(ref b) => assert_eq!(&DataType::Float64, b.data_type()),
+            _ => panic!("expected an owned array"),
+        }
+    }
+
+    #[test]
+    fn test_cast_f64_to_i64_rounding() {
+        let a = Float64Array::from(vec![0.5, 1.5, 2.5, -0.5, -1.5, 2.7, -2.7]);
+        let array = Arc::new(a) as ArrayRef;
+        let cast_rounded = |rounding| {
+            let options = CastOptions {
+                float_to_int_rounding: rounding,
+                ..Default::default()
+            };
+            let b = cast_with_options(&array, &DataType::Int64, &options).unwrap();
+            let c = b.as_any().downcast_ref::<Int64Array>().unwrap();
+            (0..c.len()).map(|i| c.value(i)).collect::<Vec<_>>()
+        };
+        assert_eq!(
+            vec![0, 1, 2, 0, -1, 2, -2],
+            cast_rounded(FloatToIntRounding::Truncate)
+        );
+        assert_eq!(
+            vec![0, 2, 2, 0, -2, 3, -3],
+            cast_rounded(FloatToIntRounding::Round)
+        );
+        assert_eq!(
+            vec![0, 1, 2, -1, -2, 2, -3],
+            cast_rounded(FloatToIntRounding::Floor)
+        );
+        assert_eq!(
+            vec![1, 2, 3, 0, -1, 3, -2],
+            cast_rounded(FloatToIntRounding::Ceil)
+        );
+    }
+
+    #[test]
+    fn test_cast_utf8_to_utf8_unicode_normalize() {
+        let array = utf8_array(vec![Some("e\u{301}"), None, Some("\u{e9}")]);
+        let options = CastOptions {
+            unicode_normalize: Some(NormalizationForm::Nfc),
+            ..Default::default()
+        };
+        let b = cast_with_options(&array, &DataType::Utf8, &options).unwrap();
+        let c = b.as_any().downcast_ref::<BinaryArray>().unwrap();
+        assert_eq!("\u{e9}".as_bytes(), c.value(0));
+        assert!(c.is_null(1));
+        assert_eq!("\u{e9}".as_bytes(), c.value(2));
+
+        // without normalization values are left as they are
+        let b = cast(&array, &DataType::Utf8).unwrap();
+        let c = b.as_any().downcast_ref::<BinaryArray>().unwrap();
+        assert_eq!("e\u{301}".as_bytes(), c.value(0));
+    }
+
+    #[test]
+    fn test_cast_utf8_to_utf8_lossy() {
+        let mut builder = BinaryBuilder::new(3);
+        for byte in b"a\xffb" {
+            builder.append_value(*byte).unwrap();
+        }
+        builder.append(true).unwrap();
+        builder.append(false).unwrap();
+        builder.append_string("ok").unwrap();
+        let array = Arc::new(builder.finish()) as ArrayRef;
+        let options = CastOptions {
+            utf8_lossy: true,
+            ..Default::default()
+        };
+        let b = cast_with_options(&array, &DataType::Utf8, &options).unwrap();
+        let c = b.as_any().downcast_ref::<BinaryArray>().unwrap();
+        assert_eq!("a\u{fffd}b".as_bytes(), c.value(0));
+        assert!(c.is_null(1));
+        assert_eq!("ok", c.get_string(2));
+
+        // without `utf8_lossy` values are left as they are
+        let b = cast(&array, &DataType::Utf8).unwrap();
+        let c = b.as_any().downcast_ref::<BinaryArray>().unwrap();
+        assert_eq!(b"a\xffb", c.value(0));
+    }
+
+    #[test]
+    fn test_cast_invalid_utf8_values_are_null() {
+        let mut builder = BinaryBuilder::new(2);
+        for byte in b"\xff1" {
+            builder.append_value(*byte).unwrap();
+        }
+        builder.append(true).unwrap();
+        let array = Arc::new(builder.finish()) as ArrayRef;
+
+        let options = CastOptions {
+            pad: Some((4, ' ', PadSide::Left)),
+            parse_json: true,
+            ..Default::default()
+        };
+        for to_type in &[
+            DataType::Utf8,
+            DataType::Boolean,
+            DataType::Time32(TimeUnit::Second),
+            DataType::List(Box::new(DataType::Utf8)),
+        ] {
+            let b = cast_with_options(&array, to_type, &options).unwrap();
+            assert!(b.is_null(0), "{:?}", to_type);
+        }
+
+        let options = CastOptions {
+            overflow: OverflowMode::Error,
+            ..Default::default()
+        };
+        assert!(cast_with_options(&array, &DataType::Boolean, &options).is_err());
+
+        let list_data = ArrayData::builder(DataType::List(Box::new(DataType::Utf8)))
+            .len(1)
+            .add_buffer(Buffer::from(&[0, 1].to_byte_slice()))
+            .add_child_data(array.data())
+            .build();
+        let list_array = Arc::new(ListArray::from(list_data)) as ArrayRef;
+        let b = cast(&list_array, &DataType::Utf8).unwrap();
+        let c = b.as_any().downcast_ref::<BinaryArray>().unwrap();
+        assert_eq!("[null]", c.get_string(0));
+    }
+
+    #[test]
+    fn test_cast_utf8_to_utf8_ascii_case() {
+        let array = utf8_array(vec![Some("abç"), None, Some("MiXeD 1")]);
+        let mut options = CastOptions {
+            ascii_case: Some(Case::Upper),
+            ..Default::default()
+        };
+        let b = cast_with_options(&array, &DataType::Utf8, &options).unwrap();
+        let c = b.as_any().downcast_ref::<BinaryArray>().unwrap();
+        assert_eq!("ABç", c.get_string(0));
+        assert!(c.is_null(1));
+        assert_eq!("MIXED 1", c.get_string(2));
+
+        options.ascii_case = Some(Case::Lower);
+        let b = cast_with_options(&array, &DataType::Utf8, &options).unwrap();
+        let c = b.as_any().downcast_ref::<BinaryArray>().unwrap();
+        assert_eq!("abç", c.get_string(0));
+        assert_eq!("mixed 1", c.get_string(2));
+        assert_eq!(
+            array.data().buffers()[0].raw_data(),
+            b.data().buffers()[0].raw_data()
+        );
+
+        // sliced arrays keep their offset
+        let sliced = array.slice(2, 1);
+        let b = cast_with_options(&sliced, &DataType::Utf8, &options).unwrap();
+        let c = b.as_any().downcast_ref::<BinaryArray>().unwrap();
+        assert_eq!(1, c.len());
+        assert_eq!("mixed 1", c.get_string(0));
+
+        // values that aren't valid UTF-8 are null, with or without other rewrites
+        let mut builder = BinaryBuilder::new(2);
+        for byte in b"a\xff" {
+            builder.append_value(*byte).unwrap();
+        }
+        builder.append(true).unwrap();
+        builder.append_string("b").unwrap();
+        let invalid = Arc::new(builder.finish()) as ArrayRef;
+        let padded = CastOptions {
+            pad: Some((1, ' ', PadSide::Left)),
+            ..options.clone()
+        };
+        for options in &[&options, &padded] {
+            let b = cast_with_options(&invalid, &DataType::Utf8, options).unwrap();
+            let c = b.as_any().downcast_ref::<BinaryArray>().unwrap();
+            assert!(c.is_null(0));
+            assert_eq!("b", c.get_string(1));
+        }
+
+        // combined with other rewrites the case is converted after decoding
+        options.url_decode = true;
+        let array = utf8_array(vec![Some("%41b")]);
+        let b = cast_with_options(&array, &DataType::Utf8, &options).unwrap();
+        let c = b.as_any().downcast_ref::<BinaryArray>().unwrap();
+        assert_eq!("ab", c.get_string(0));
+    }
+
+    #[test]
+    fn test_cast_utf8_to_utf8_url_decode() {
+        let array = utf8_array(vec![Some("%20a%2Fb"), Some("100%zz%21"), None]);
+        let mut options = CastOptions {
+            url_decode: true,
+            ..Default::default()
+        };
+        let b = cast_with_options(&array, &DataType::Utf8, &options).unwrap();
+        let c = b.as_any().downcast_ref::<BinaryArray>().unwrap();
+        assert_eq!(" a/b", c.get_string(0));
+        assert_eq!("100%zz!", c.get_string(1));
+        assert!(c.is_null(2));
+
+        options.url_decode_strict = true;
+        assert_eq!(
+            ArrowError::ComputeError(
+                "Invalid percent-encoding in \"100%zz%21\"".to_string()
+            ),
+            cast_with_options(&array, &DataType::Utf8, &options)
+                .err()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_cast_forbid_source_nulls() {
+        let options = CastOptions {
+            forbid_source_nulls: true,
+            ..Default::default()
+        };
+        let array = Arc::new(Int32Array::from(vec![Some(1), None, None])) as ArrayRef;
+        assert_eq!(
+            ArrowError::ComputeError(
+                "Cannot cast an array with nulls, found a null at index 1".to_string()
+            ),
+            cast_with_options(&array, &DataType::Int64, &options)
+                .err()
+                .unwrap()
+        );
+
+        let array = Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef;
+        let b = cast_with_options(&array, &DataType::Int64, &options).unwrap();
+        assert_eq!(2, b.len());
+
+        // nulls that parsing JSON produces are not source nulls
+        let options = CastOptions {
+            forbid_source_nulls: true,
+            parse_json: true,
+            ..Default::default()
+        };
+        let array = utf8_array(vec![Some("not json"), Some("[1,null]")]);
+        let to_type = DataType::List(Box::new(DataType::Int32));
+        let b = cast_with_options(&array, &to_type, &options).unwrap();
+        let c = b.as_any().downcast_ref::<ListArray>().unwrap();
+        assert!(c.is_null(0));
+        assert_eq!(2, c.value_length(1));
+        assert!(c.values().is_null(1));
+    }
+
+    #[test]
+    fn test_cast_struct_to_utf8_json() {
+        let a = Arc::new(Int32Array::from(vec![Some(1), None, Some(3)])) as ArrayRef;
+        let b = utf8_array(vec![Some("x"), Some("y \"z\""), None]);
+        let fields = vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ];
+        let struct_data = ArrayData::builder(DataType::Struct(fields))
+            .len(3)
+            .null_count(1)
+            .null_bit_buffer(Buffer::from([0b011]))
+            .add_child_data(a.data())
+            .add_child_data(b.data())
+            .build();
+        let array = Arc::new(StructArray::from(struct_data)) as ArrayRef;
+
+        let c = cast(&array, &DataType::Utf8).unwrap();
+        let c = c.as_any().downcast_ref::<BinaryArray>().unwrap();
+        assert_eq!("{\"a\":1,\"b\":\"x\"}", c.get_string(0));
+        assert_eq!("{\"a\":null,\"b\":\"y \\\"z\\\"\"}", c.get_string(1));
+        assert!(c.is_null(2));
+    }
+
+    #[test]
+    fn test_cast_list_of_struct() {
+        let a = Int32Array::from(vec![Some(1), None, Some(3), Some(4)]);
+        let fields = vec![Field::new("a", DataType::Int32, true)];
+        let struct_data = ArrayData::builder(DataType::Struct(fields))
+            .len(4)
+            .null_count(1)
+            .null_bit_buffer(Buffer::from([0b1011]))
+            .add_child_data(a.data())
+            .build();
+        let value_offsets = Buffer::from(&[0, 2, 2, 4].to_byte_slice());
+        let from_type = DataType::List(Box::new(struct_data.data_type().clone()));
+        let list_data = ArrayData::builder(from_type)
+            .len(3)
+            .null_count(1)
+            .null_bit_buffer(Buffer::from([0b101]))
+            .add_buffer(value_offsets)
+            .add_child_data(struct_data)
+            .build();
+        let list_array = Arc::new(ListArray::from(list_data)) as ArrayRef;
+
+        let to_struct = DataType::Struct(vec![Field::new("a", DataType::Int64, true)]);
+        let to_type = DataType::List(Box::new(to_struct.clone()));
+        let b = cast(&list_array, &to_type).unwrap();
+        assert_eq!(&to_type, b.data_type());
+        assert!(b.is_valid(0));
+        assert!(b.is_null(1));
+        assert!(b.is_valid(2));
+
+        let list = b.as_any().downcast_ref::<ListArray>().unwrap();
+        assert_eq!(
+            vec![0, 2, 2, 4],
+            (0..4).map(|i| list.value_offset(i)).collect::<Vec<_>>()
+        );
+        let values = list.values();
+        assert_eq!(&to_struct, values.data_type());
+        let structs = values.as_any().downcast_ref::<StructArray>().unwrap();
+        assert!(structs.is_valid(0));
+        assert!(structs.is_valid(1));
+        assert!(structs.is_null(2));
+        assert!(structs.is_valid(3));
+        let c = structs.column(0);
+        let c = c.as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(1, c.value(0));
+        assert!(c.is_null(1));
+        assert_eq!(4, c.value(3));
+
+        let to_type = DataType::Struct(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Int64, true),
+        ]);
+        assert_eq!(
+            ArrowError::ComputeError(
+                "Cannot cast a struct with 1 fields to a struct with 2 fields"
+                    .to_string()
+            ),
+            cast(&values, &to_type).err().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_cast_struct_with_identity_list_field_shares_buffers() {
+        let value_data = Int32Array::from(vec![0, 1, 2]).data();
+        let value_offsets = Buffer::from(&[0, 2, 3].to_byte_slice());
+        let list_type = DataType::List(Box::new(DataType::Int32));
+        let list_data = ArrayData::builder(list_type.clone())
+            .len(2)
+            .add_buffer(value_offsets.clone())
+            .add_child_data(value_data.clone())
+            .build();
+        let b = Int32Array::from(vec![1, 2]);
+        let struct_data = ArrayData::builder(DataType::Struct(vec![
+            Field::new("a", list_type.clone(), true),
+            Field::new("b", DataType::Int32, true),
+        ]))
+        .len(2)
+        .add_child_data(list_data)
+        .add_child_data(b.data())
+        .build();
+        let array = Arc::new(StructArray::from(struct_data)) as ArrayRef;
+
+        // only the second field changes type, so the list field is cast to itself
+        let to_type = DataType::Struct(vec![
+            Field::new("a", list_type, true),
+            Field::new("b", DataType::Int64, true),
+        ]);
+        let cast_array = cast(&array, &to_type).unwrap();
+        let list_data = cast_array.data().child_data()[0].clone();
+        assert_eq!(value_offsets.raw_data(), list_data.buffers()[0].raw_data());
+        assert_eq!(
+            value_data.buffers()[0].raw_data(),
+            list_data.child_data()[0].buffers()[0].raw_data()
+        );
+    }
+
+    #[test]
+    fn test_cast_list_to_utf8_json() {
+        let value_data = utf8_array(vec![Some("a"), Some("b"), None]).data();
+        let value_offsets = Buffer::from(&[0, 2, 2, 3].to_byte_slice());
+        let list_data = ArrayData::builder(DataType::List(Box::new(DataType::Utf8)))
+            .len(3)
+            .null_count(1)
+            .null_bit_buffer(Buffer::from([0b101]))
+            .add_buffer(value_offsets)
+            .add_child_data(value_data)
+            .build();
+        let list_array = Arc::new(ListArray::from(list_data)) as ArrayRef;
+
+        let b = cast(&list_array, &DataType::Utf8).unwrap();
+        let c = b.as_any().downcast_ref::<BinaryArray>().unwrap();
+        assert_eq!("[\"a\",\"b\"]", c.get_string(0));
+        assert!(c.is_null(1));
+        assert_eq!("[null]", c.get_string(2));
+    }
+
+    #[test]
+    fn test_cast_int_to_interval_year_month() {
+        let a = Int64Array::from(vec![Some(12), None, Some(-3)]);
+        let array = Arc::new(a) as ArrayRef;
+        let to_type = DataType::Interval(IntervalUnit::YearMonth);
+        let b = cast(&array, &to_type).unwrap();
+        assert_eq!(&to_type, b.data_type());
+        assert_eq!(
+            array.data().buffers()[0].raw_data(),
+            b.data().buffers()[0].raw_data()
+        );
+        let c = cast(&b, &DataType::Int64).unwrap();
+        assert_eq!(
+            array.data().buffers()[0].raw_data(),
+            c.data().buffers()[0].raw_data()
+        );
+
+        let a = Int32Array::from(vec![Some(12), None, Some(-3)]);
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &to_type).unwrap();
+        let c = cast(&b, &DataType::Int32).unwrap();
+        let c = c.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(12, c.value(0));
+        assert!(c.is_null(1));
+        assert_eq!(-3, c.value(2));
+    }
+
+    #[test]
+    fn test_cast_utf8_to_numeric_parse_default() {
+        let array = utf8_array(vec![Some("5"), Some("x"), None]);
+        let options = CastOptions {
+            parse_default: Some(-1),
+            parse_default_float: Some(std::f64::NAN),
+            ..Default::default()
+        };
+        let b = cast_with_options(&array, &DataType::Int32, &options).unwrap();
+        let c = b.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(5, c.value(0));
+        assert_eq!(-1, c.value(1));
+        assert!(c.is_null(2));
+
+        let b = cast_with_options(&array, &DataType::Float64, &options).unwrap();
+        let c = b.as_any().downcast_ref::<Float64Array>().unwrap();
+        assert_eq!(5.0, c.value(0));
+        assert!(c.value(1).is_nan());
+        assert!(c.is_null(2));
+
+        // -1 doesn't fit into an unsigned type
+        let b = cast_with_options(&array, &DataType::UInt8, &options).unwrap();
+        assert!(b.is_null(1));
+    }
+
+    #[test]
+    fn test_cast_f64_canonicalize_nan() {
+        let signaling_nan = f64::from_bits(0x7ff0_0000_0000_0001);
+        assert!(signaling_nan.is_nan());
+        let a = Float64Array::from(vec![Some(signaling_nan), Some(1.5), None]);
+        let array = Arc::new(a) as ArrayRef;
+        let options = CastOptions {
+            canonicalize_nan: true,
+            ..Default::default()
+        };
+        let b = cast_with_options(&array, &DataType::Float64, &options).unwrap();
+        let c = b.as_any().downcast_ref::<Float64Array>().unwrap();
+        assert_eq!(std::f64::NAN.to_bits(), c.value(0).to_bits());
+        assert_eq!(1.5, c.value(1));
+        assert!(c.is_null(2));
+
+        // without the option the array is returned as it is
+        let b = cast(&array, &DataType::Float64).unwrap();
+        let c = b.as_any().downcast_ref::<Float64Array>().unwrap();
+        assert_eq!(signaling_nan.to_bits(), c.value(0).to_bits());
+    }
+
+    #[test]
+    fn test_cast_utf8_to_list_parse_json() {
+        let array = utf8_array(vec![
+            Some("[1,2,3]"),
+            Some("[]"),
+            Some("not json"),
+            Some("[4,\"x\",null]"),
+            None,
+        ]);
+        let options = CastOptions {
+            parse_json: true,
+            ..Default::default()
+        };
+        let to_type = DataType::List(Box::new(DataType::Int32));
+        let b = cast_with_options(&array, &to_type, &options).unwrap();
+        assert_eq!(&to_type, b.data_type());
+        let c = b.as_any().downcast_ref::<ListArray>().unwrap();
+        assert_eq!(3, c.value_length(0));
+        assert!(c.is_valid(1));
+        assert_eq!(0, c.value_length(1));
+        assert!(c.is_null(2));
+        assert!(c.is_valid(3));
+        assert_eq!(3, c.value_length(3));
+        assert!(c.is_null(4));
+
+        let values = c.values();
+        let values = values.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(1, values.value(0));
+        assert_eq!(2, values.value(1));
+        assert_eq!(3, values.value(2));
+        assert_eq!(4, values.value(3));
+        assert!(values.is_null(4));
+        assert!(values.is_null(5));
+    }
+
+    #[test]
+    fn test_cast_utf8_to_numeric_ascii_fast_path() {
+        let values: Vec<Option<String>> = (0..100_000)
+            .map(|i| match i % 11 {
+                0 => None,
+                1 => Some(format!("{}.5", i)),
+                2 => Some(format!("-{}", i)),
+                3 => Some(format!("{}é", i)),
+                4 => Some("\u{ff11}".to_string()),
+                _ => Some(i.to_string()),
+            })
+            .collect();
+        let strings: Vec<Option<&str>> = values
+            .iter()
+            .map(|v| v.as_ref().map(|s| s.as_str()))
+            .collect();
+        let array = utf8_array(strings.clone());
+
+        // compare with parsing each value through a validated `&str`
+        let b = cast(&array, &DataType::Int32).unwrap();
+        let c = b.as_any().downcast_ref::<Int32Array>().unwrap();
+        for (i, v) in strings.iter().enumerate() {
+            match v.and_then(|v| v.parse::<i32>().ok()) {
+                Some(expected) => assert_eq!(expected, c.value(i)),
+                None => assert!(c.is_null(i)),
+            }
+        }
+
+        let b = cast(&array, &DataType::Float64).unwrap();
+        let c = b.as_any().downcast_ref::<Float64Array>().unwrap();
+        for (i, v) in strings.iter().enumerate() {
+            match v.and_then(|v| v.parse::<f64>().ok()) {
+                Some(expected) => assert_eq!(expected, c.value(i)),
+                None => assert!(c.is_null(i)),
+            }
+        }
+    }
+
+    #[test]
+    fn test_cast_all_null_i32_to_f64() {
+        let a = Int32Array::from(vec![None; 100]);
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::Float64).unwrap();
+        assert_eq!(&DataType::Float64, b.data_type());
+        assert_eq!(100, b.len());
+        assert_eq!(100, b.null_count());
+        let c = b.as_any().downcast_ref::<Float64Array>().unwrap();
+        assert!((0..c.len()).all(|i| c.is_null(i)));
+
+        let b = cast(&array.slice(10, 20), &DataType::UInt8).unwrap();
+        assert_eq!(20, b.len());
+        assert_eq!(20, b.null_count());
+
+        let array = Arc::new(Int64Array::from(vec![None; 10])) as ArrayRef;
+        for to_type in &[
+            DataType::Utf8,
+            DataType::Boolean,
+            DataType::Time64(TimeUnit::Nanosecond),
+            DataType::Timestamp(TimeUnit::Second),
+        ] {
+            let b = cast(&array, to_type).unwrap();
+            assert_eq!(to_type, b.data_type());
+            assert_eq!(10, b.len());
+            assert_eq!(10, b.null_count());
+        }
+        let b = cast(&array, &DataType::Utf8).unwrap();
+        let c = b.as_any().downcast_ref::<BinaryArray>().unwrap();
+        assert_eq!(0, c.value_length(9));
+
+        let timestamps = cast(&array, &DataType::Timestamp(TimeUnit::Second)).unwrap();
+        let b = cast(&timestamps, &DataType::Timestamp(TimeUnit::Millisecond)).unwrap();
+        assert_eq!(10, b.null_count());
+        let b = cast(&array, &DataType::Float64).unwrap();
+        let b = cast(&b, &DataType::Utf8).unwrap();
+        assert_eq!(10, b.null_count());
+
+        let dates = cast(&array, &DataType::Date64(DateUnit::Millisecond)).unwrap();
+        let options = CastOptions {
+            date_epoch_shift_days: 1,
+            ..Default::default()
+        };
+        let b = cast_with_options(&dates, dates.data_type(), &options).unwrap();
+        assert_eq!(10, b.null_count());
+    }
+
+    #[test]
+    fn test_cast_batches() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ]));
+        let batch = |a: Vec<Option<i32>>, b: Vec<Option<&str>>| {
+            let columns = vec![Arc::new(Int32Array::from(a)) as ArrayRef, utf8_array(b)];
+            RecordBatch::try_new(schema.clone(), columns).unwrap()
+        };
+        let batches = vec![
+            batch(vec![Some(1), None], vec![Some("1.5"), Some("x")]),
+            batch(vec![Some(3)], vec![None]),
+        ];
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::buffer::Buffer;
+        let target = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Float64, true),
+        ]));
+        let converted = cast_batches(&batches, &target).unwrap();
+        assert_eq!(2, converted.len());
+        assert!(Arc::ptr_eq(&target, converted[0].schema()));
+        assert!(Arc::ptr_eq(converted[0].schema(), converted[1].schema()));
+        assert_eq!(&DataType::Int64, converted[0].schema().field(0).data_type());
+
+        let a = converted[0].column(0);
+        let a = a.as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(1, a.value(0));
+        assert!(a.is_null(1));
+        let b = converted[0].column(1);
+        let b = b.as_any().downcast_ref::<Float64Array>().unwrap();
+        assert_eq!(1.5, b.value(0));
+        assert!(b.is_null(1));
+        assert_eq!(1, converted[1].num_rows());
+
+        let target = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, true)]));
+        assert_eq!(
+            ArrowError::InvalidArgumentError(
+                "Cannot cast a record batch with 2 columns to a schema with 1 fields"
+                    .to_string()
+            ),
+            cast_batches(&batches, &target).err().unwrap()
+        );
+
+        let target = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Float64, true),
+        ]));
+        let options = CastOptions {
+            parse_default_float: Some(0.0),
+            ..Default::default()
+        };
+        let converted = cast_batches_with_options(&batches, &target, &options).unwrap();
+        let b = converted[0].column(1);
+        let b = b.as_any().downcast_ref::<Float64Array>().unwrap();
+        assert_eq!(1.5, b.value(0));
+        assert_eq!(0.0, b.value(1));
+    }
 
     #[test]
-    fn test_cast_i32_to_f64() {
-        let a = Int32Array::from(vec![5, 6, 7, 8, 9]);
+    fn test_cast_f64_to_f32_precision_loss() {
+        let options = CastOptions {
+            error_on_float_precision_loss: true,
+            ..Default::default()
+        };
+        let a = Float64Array::from(vec![Some(0.5), None, Some(0.1)]);
         let array = Arc::new(a) as ArrayRef;
-        let b = cast(&array, &DataType::Float64).unwrap();
-        let c = b.as_any().downcast_ref::<Float64Array>().unwrap();
-        assert_eq!(5.0, c.value(0));
-        assert_eq!(6.0, c.value(1));
-        assert_eq!(7.0, c.value(2));
-        assert_eq!(8.0, c.value(3));
-        assert_eq!(9.0, c.value(4));
+        assert_eq!(
+            ArrowError::ComputeError(
+                "Value 0.1 at index 2 can't be represented exactly as Float32"
+                    .to_string()
+            ),
+            cast_with_options(&array, &DataType::Float32, &options)
+                .err()
+                .unwrap()
+        );
+        // the precision loss is silent by default
+        let b = cast(&array, &DataType::Float32).unwrap();
+        let c = b.as_any().downcast_ref::<Float32Array>().unwrap();
+        assert_eq!(0.1, c.value(2));
+
+        let a = Float64Array::from(vec![
+            Some(0.5),
+            None,
+            Some(-1024.25),
+            Some(std::f64::NAN),
+        ]);
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast_with_options(&array, &DataType::Float32, &options).unwrap();
+        let c = b.as_any().downcast_ref::<Float32Array>().unwrap();
+        assert_eq!(0.5, c.value(0));
+        assert!(c.is_null(1));
+        assert_eq!(-1024.25, c.value(2));
+        assert!(c.value(3).is_nan());
     }
 
     #[test]
-    fn test_cast_i32_to_u8() {
-        let a = Int32Array::from(vec![-5, 6, -7, 8, 100000000]);
+    fn test_cast_constant_i32_to_bool() {
+        let options = CastOptions {
+            constant_hint: true,
+            ..Default::default()
+        };
+        let array = Arc::new(Int32Array::from(vec![5; 10_000])) as ArrayRef;
+        let b = cast_with_options(&array, &DataType::Boolean, &options).unwrap();
+        let c = b.as_any().downcast_ref::<BooleanArray>().unwrap();
+        assert_eq!(10_000, c.len());
+        assert_eq!(0, c.null_count());
+        assert!((0..c.len()).all(|i| c.value(i)));
+
+        let a = Int32Array::from(vec![Some(0), None, Some(0)]);
         let array = Arc::new(a) as ArrayRef;
-        let b = cast(&array, &DataType::UInt8).unwrap();
-        let c = b.as_any().downcast_ref::<UInt8Array>().unwrap();
-        assert_eq!(false, c.is_valid(0));
-        assert_eq!(6, c.value(1));
-        assert_eq!(false, c.is_valid(2));
-        assert_eq!(8, c.value(3));
-        // overflows return None
-        assert_eq!(false, c.is_valid(4));
+        let b =
+            cast_with_options(&array.slice(1, 2), &DataType::Boolean, &options).unwrap();
+        let c = b.as_any().downcast_ref::<BooleanArray>().unwrap();
+        assert!(c.is_null(0));
+        assert_eq!(false, c.value(1));
+
+        // the values behind null slots don't break the hint
+        let a = Int32Array::from(vec![Some(5), None, Some(7)]);
+        let array = Arc::new(a) as ArrayRef;
+        let from = array.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(
+            Some(true),
+            numeric_to_bool_constant_hint(from, &|v: i32| v != 0)
+        );
+
+        // arrays that aren't constant are cast value by value
+        let array = Arc::new(Int32Array::from(vec![Some(0), Some(2), None])) as ArrayRef;
+        let b = cast_with_options(&array, &DataType::Boolean, &options).unwrap();
+        let c = b.as_any().downcast_ref::<BooleanArray>().unwrap();
+        assert_eq!(false, c.value(0));
+        assert_eq!(true, c.value(1));
+        assert!(c.is_null(2));
     }
 
     #[test]
-    fn test_cast_i32_to_u8_sliced() {
-        let a = Int32Array::from(vec![-5, 6, -7, 8, 100000000]);
+    fn test_cast_float_to_64_bit_int_boundaries() {
+        let saturate = CastOptions {
+            overflow: OverflowMode::Saturate,
+            ..Default::default()
+        };
+        // the largest f64 below 2^63, and 2^63 which is `i64::MAX as f64`
+        let below_max = 9_223_372_036_854_774_784.0;
+        let a = Float64Array::from(vec![
+            std::i64::MIN as f64,
+            below_max,
+            std::i64::MAX as f64,
+        ]);
         let array = Arc::new(a) as ArrayRef;
-        assert_eq!(0, array.offset());
-        let array = array.slice(2, 3);
-        assert_eq!(2, array.offset());
-        let b = cast(&array, &DataType::UInt8).unwrap();
-        assert_eq!(3, b.len());
-        assert_eq!(0, b.offset());
-        let c = b.as_any().downcast_ref::<UInt8Array>().unwrap();
-        assert_eq!(false, c.is_valid(0));
-        assert_eq!(8, c.value(1));
-        // overflows return None
-        assert_eq!(false, c.is_valid(2));
+        let b = cast(&array, &DataType::Int64).unwrap();
+        let c = b.as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(std::i64::MIN, c.value(0));
+        assert_eq!(9_223_372_036_854_774_784, c.value(1));
+        assert!(c.is_null(2));
+        let b = cast_with_options(&array, &DataType::Int64, &saturate).unwrap();
+        let c = b.as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(std::i64::MIN, c.value(0));
+        assert_eq!(9_223_372_036_854_774_784, c.value(1));
+        assert_eq!(std::i64::MAX, c.value(2));
+
+        // 2^64 is `u64::MAX as f64`
+        let a = Float64Array::from(vec![0.0, std::u64::MAX as f64]);
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::UInt64).unwrap();
+        let c = b.as_any().downcast_ref::<UInt64Array>().unwrap();
+        assert_eq!(0, c.value(0));
+        assert!(c.is_null(1));
+        let b = cast_with_options(&array, &DataType::UInt64, &saturate).unwrap();
+        let c = b.as_any().downcast_ref::<UInt64Array>().unwrap();
+        assert_eq!(std::u64::MAX, c.value(1));
+
+        let a = Float32Array::from(vec![std::i64::MIN as f32, std::i64::MAX as f32]);
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::Int64).unwrap();
+        let c = b.as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(std::i64::MIN, c.value(0));
+        assert!(c.is_null(1));
+        let b = cast_with_options(&array, &DataType::Int64, &saturate).unwrap();
+        let c = b.as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(std::i64::MAX, c.value(1));
+
+        let a = Float32Array::from(vec![std::u64::MAX as f32]);
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::UInt64).unwrap();
+        assert!(b.is_null(0));
+        let b = cast_with_options(&array, &DataType::UInt64, &saturate).unwrap();
+        let c = b.as_any().downcast_ref::<UInt64Array>().unwrap();
+        assert_eq!(std::u64::MAX, c.value(0));
     }
 
     #[test]
-    fn test_cast_i32_to_i32() {
-        let a = Int32Array::from(vec![5, 6, 7, 8, 9]);
-        let array = Arc::new(a) as ArrayRef;
-        let b = cast(&array, &DataType::Int32).unwrap();
+    fn test_cast_string_to_numeric_count_failures() {
+        let array = utf8_array(vec![Some("1"), Some("x"), None, Some("2")]);
+        let (b, failures) =
+            cast_string_to_numeric_count_failures(&array, &DataType::Int32).unwrap();
+        assert_eq!(1, failures);
         let c = b.as_any().downcast_ref::<Int32Array>().unwrap();
-        assert_eq!(5, c.value(0));
-        assert_eq!(6, c.value(1));
-        assert_eq!(7, c.value(2));
-        assert_eq!(8, c.value(3));
-        assert_eq!(9, c.value(4));
+        assert_eq!(1, c.value(0));
+        assert!(c.is_null(1));
+        assert!(c.is_null(2));
+        assert_eq!(2, c.value(3));
+
+        let array = Arc::new(Int32Array::from(vec![1])) as ArrayRef;
+        assert_eq!(
+            ArrowError::InvalidArgumentError(
+                "Expected a Utf8 array, found Int32".to_string()
+            ),
+            cast_string_to_numeric_count_failures(&array, &DataType::Int64)
+                .err()
+                .unwrap()
+        );
+
+        let array = utf8_array(vec![Some("true")]);
+        for to_type in &[
+            DataType::Boolean,
+            DataType::Utf8,
+            DataType::List(Box::new(DataType::Int32)),
+        ] {
+            assert_eq!(
+                ArrowError::CastNotSupported {
+                    from: DataType::Utf8,
+                    to: to_type.clone(),
+                },
+                cast_string_to_numeric_count_failures(&array, to_type)
+                    .err()
+                    .unwrap()
+            );
+        }
     }
 
     #[test]
-    fn test_cast_i32_to_list_i32() {
-        let a = Int32Array::from(vec![5, 6, 7, 8, 9]);
+    fn test_cast_with_validity() {
+        let a = BooleanArray::from(vec![
+            Some(true),
+            Some(false),
+            None,
+            Some(true),
+            Some(false),
+        ]);
         let array = Arc::new(a) as ArrayRef;
-        let b = cast(&array, &DataType::List(Box::new(DataType::Int32))).unwrap();
-        assert_eq!(5, b.len());
-        let arr = b.as_any().downcast_ref::<ListArray>().unwrap();
-        assert_eq!(0, arr.value_offset(0));
-        assert_eq!(1, arr.value_offset(1));
-        assert_eq!(2, arr.value_offset(2));
-        assert_eq!(3, arr.value_offset(3));
-        assert_eq!(4, arr.value_offset(4));
-        assert_eq!(1, arr.value_length(0));
-        assert_eq!(1, arr.value_length(1));
-        assert_eq!(1, arr.value_length(2));
-        assert_eq!(1, arr.value_length(3));
-        assert_eq!(1, arr.value_length(4));
-        let values = arr.values();
-        let c = values.as_any().downcast_ref::<Int32Array>().unwrap();
-        assert_eq!(5, c.value(0));
-        assert_eq!(6, c.value(1));
-        assert_eq!(7, c.value(2));
-        assert_eq!(8, c.value(3));
-        assert_eq!(9, c.value(4));
+        let validity = Buffer::from([0b10101]);
+
+        let b = cast_with_validity(&array, &DataType::Boolean, &validity).unwrap();
+        assert_eq!(3, b.null_count());
+        let c = b.as_any().downcast_ref::<BooleanArray>().unwrap();
+        assert_eq!(true, c.value(0));
+        assert!(c.is_null(1));
+        assert!(c.is_null(2));
+        assert!(c.is_null(3));
+        assert_eq!(false, c.value(4));
+
+        let b =
+            cast_with_validity(&array.slice(1, 4), &DataType::Int32, &validity).unwrap();
+        assert_eq!(2, b.null_count());
+        let c = b.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(0, c.value(0));
+        assert!(c.is_null(1));
+        assert_eq!(1, c.value(2));
+        assert!(c.is_null(3));
+
+        let validity = Buffer::from([]);
+        assert_eq!(
+            ArrowError::InvalidArgumentError(
+                "Validity buffer has 0 bytes, expected at least 1".to_string()
+            ),
+            cast_with_validity(&array, &DataType::Boolean, &validity)
+                .err()
+                .unwrap()
+        );
     }
 
     #[test]
-    fn test_cast_i32_to_list_i32_nullable() {
-        let a = Int32Array::from(vec![Some(5), None, Some(7), Some(8), Some(9)]);
-        let array = Arc::new(a) as ArrayRef;
-        let b = cast(&array, &DataType::List(Box::new(DataType::Int32))).unwrap();
-        assert_eq!(5, b.len());
-        assert_eq!(1, b.null_count());
-        let arr = b.as_any().downcast_ref::<ListArray>().unwrap();
-        assert_eq!(0, arr.value_offset(0));
-        assert_eq!(1, arr.value_offset(1));
-        assert_eq!(2, arr.value_offset(2));
-        assert_eq!(3, arr.value_offset(3));
-        assert_eq!(4, arr.value_offset(4));
-        assert_eq!(1, arr.value_length(0));
-        assert_eq!(1, arr.value_length(1));
-        assert_eq!(1, arr.value_length(2));
-        assert_eq!(1, arr.value_length(3));
-        assert_eq!(1, arr.value_length(4));
-        let values = arr.values();
-        let c = values.as_any().downcast_ref::<Int32Array>().unwrap();
-        assert_eq!(1, c.null_count());
-        assert_eq!(5, c.value(0));
-        assert_eq!(false, c.is_valid(1));
-        assert_eq!(7, c.value(2));
-        assert_eq!(8, c.value(3));
-        assert_eq!(9, c.value(4));
+    fn test_cast_utf8_strip_quotes() {
+        let array = utf8_array(vec![
+            Some("\"42\""),
+            Some("\"4\"2"),
+            Some("\""),
+            None,
+            Some("7"),
+        ]);
+        let options = CastOptions {
+            strip_quotes: true,
+            ..Default::default()
+        };
+        let b = cast_with_options(&array, &DataType::Int32, &options).unwrap();
+        let c = b.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(42, c.value(0));
+        assert!(c.is_null(1));
+        assert!(c.is_null(2));
+        assert!(c.is_null(3));
+        assert_eq!(7, c.value(4));
+
+        // quoted values are not parsed by default
+        let b = cast(&array, &DataType::Int32).unwrap();
+        assert!(b.is_null(0));
+
+        let array = utf8_array(vec![Some("\"TRUE\""), Some("\"f\"")]);
+        let b = cast_with_options(&array, &DataType::Boolean, &options).unwrap();
+        let c = b.as_any().downcast_ref::<BooleanArray>().unwrap();
+        assert_eq!(true, c.value(0));
+        assert_eq!(false, c.value(1));
     }
 
     #[test]
-    #[should_panic(
-        expected = "Cast kernel does not yet support sliced (non-zero offset) arrays"
-    )]
-    fn test_cast_i32_to_list_i32_nullable_sliced() {
-        let a = Int32Array::from(vec![Some(5), None, Some(7), Some(8), None]);
+    fn test_cast_int_to_bool_bit() {
+        let a =
+            Int32Array::from(vec![Some(0b100), Some(0b011), Some(0b000), None, Some(-1)]);
         let array = Arc::new(a) as ArrayRef;
-        let array = array.slice(2, 3);
-        let b = cast(&array, &DataType::List(Box::new(DataType::Int32))).unwrap();
-        assert_eq!(3, b.len());
-        assert_eq!(1, b.null_count());
-        let arr = b.as_any().downcast_ref::<ListArray>().unwrap();
-        assert_eq!(0, arr.value_offset(0));
-        assert_eq!(1, arr.value_offset(1));
-        assert_eq!(2, arr.value_offset(2));
-        assert_eq!(1, arr.value_length(0));
-        assert_eq!(1, arr.value_length(1));
-        assert_eq!(1, arr.value_length(2));
-        let values = arr.values();
-        let c = values.as_any().downcast_ref::<Int32Array>().unwrap();
-        assert_eq!(1, c.null_count());
-        assert_eq!(7, c.value(0));
-        assert_eq!(8, c.value(1));
-        // if one removes the non-zero-offset limitation, this assertion passes when it
-        // shouldn't
-        assert_eq!(0, c.value(2));
+        let options = CastOptions {
+            bool_bit: Some(2),
+            ..Default::default()
+        };
+        let b = cast_with_options(&array, &DataType::Boolean, &options).unwrap();
+        let c = b.as_any().downcast_ref::<BooleanArray>().unwrap();
+        assert_eq!(true, c.value(0));
+        assert_eq!(false, c.value(1));
+        assert_eq!(false, c.value(2));
+        assert!(c.is_null(3));
+        assert_eq!(true, c.value(4));
+
+        let options = CastOptions {
+            bool_bit: Some(32),
+            ..Default::default()
+        };
+        assert_eq!(
+            ArrowError::InvalidArgumentError(
+                "Cannot test bit 32 of Int32 values".to_string()
+            ),
+            cast_with_options(&array, &DataType::Boolean, &options)
+                .err()
+                .unwrap()
+        );
     }
 
     #[test]
-    fn test_cast_utf_to_i32() {
-        let a = BinaryArray::from(vec!["5", "6", "seven", "8", "9.1"]);
-        let array = Arc::new(a) as ArrayRef;
-        let b = cast(&array, &DataType::Int32).unwrap();
+    fn test_cast_utf8_strip_nonnumeric_suffix() {
+        let array = utf8_array(vec![
+            Some("42ms"),
+            Some("3.5kg"),
+            Some("kg"),
+            None,
+            Some("7"),
+        ]);
+        let options = CastOptions {
+            strip_nonnumeric_suffix: true,
+            ..Default::default()
+        };
+        let b = cast_with_options(&array, &DataType::Int32, &options).unwrap();
         let c = b.as_any().downcast_ref::<Int32Array>().unwrap();
-        assert_eq!(5, c.value(0));
-        assert_eq!(6, c.value(1));
-        assert_eq!(false, c.is_valid(2));
-        assert_eq!(8, c.value(3));
-        assert_eq!(false, c.is_valid(2));
+        assert_eq!(42, c.value(0));
+        assert!(c.is_null(1));
+        assert!(c.is_null(2));
+        assert!(c.is_null(3));
+        assert_eq!(7, c.value(4));
+
+        let b = cast_with_options(&array, &DataType::Float64, &options).unwrap();
+        let c = b.as_any().downcast_ref::<Float64Array>().unwrap();
+        assert_eq!(42.0, c.value(0));
+        assert_eq!(3.5, c.value(1));
+
+        // suffixes are not stripped by default
+        let b = cast(&array, &DataType::Int32).unwrap();
+        assert!(b.is_null(0));
+    }
+
+    #[test]
+    fn test_cast_tiny_floats_to_int() {
+        let subnormal = 1e-310;
+        assert!(subnormal > 0.0 && !f64::is_normal(subnormal));
+        let a =
+            Float64Array::from(vec![std::f64::MIN_POSITIVE, subnormal, -subnormal, -0.0]);
+        let array = Arc::new(a) as ArrayRef;
+        for overflow in &[
+            OverflowMode::Null,
+            OverflowMode::Saturate,
+            OverflowMode::Error,
+        ] {
+            for rounding in &[FloatToIntRounding::Truncate, FloatToIntRounding::Round] {
+                let options = CastOptions {
+                    overflow: *overflow,
+                    float_to_int_rounding: *rounding,
+                    ..Default::default()
+                };
+                let b = cast_with_options(&array, &DataType::Int64, &options).unwrap();
+                assert_eq!(0, b.null_count());
+                let c = b.as_any().downcast_ref::<Int64Array>().unwrap();
+                assert!((0..c.len()).all(|i| c.value(i) == 0));
+            }
+        }
+
+        let a = Float32Array::from(vec![std::f32::MIN_POSITIVE, 1e-40]);
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::UInt8).unwrap();
+        assert_eq!(0, b.null_count());
+        let c = b.as_any().downcast_ref::<UInt8Array>().unwrap();
+        assert_eq!(0, c.value(0));
+        assert_eq!(0, c.value(1));
     }
 
     #[test]
@@ -696,7 +4731,7 @@ mod tests {
 
     #[test]
     #[should_panic(
-        expected = "Casting from Int32 to Timestamp(Microsecond) not supported"
+        expected = "CastNotSupported { from: Int32, to: Timestamp(Microsecond) }"
     )]
     fn test_cast_int32_to_timestamp() {
         let a = Int32Array::from(vec![Some(2), Some(10), None]);
@@ -723,8 +4758,8 @@ mod tests {
         let cast_array =
             cast(&list_array, &DataType::List(Box::new(DataType::UInt16))).unwrap();
         // 3 negative values should get lost when casting to unsigned,
-        // 1 value should overflow
-        assert_eq!(4, cast_array.null_count());
+        // 1 value should overflow, but the lists themselves stay valid
+        assert_eq!(0, cast_array.null_count());
         // offsets should be the same
         assert_eq!(
             list_array.data().buffers().to_vec(),
@@ -755,9 +4790,287 @@ mod tests {
         assert_eq!(false, u16arr.is_valid(7));
     }
 
+    #[test]
+    fn test_cast_list_i32_to_list_i8_keeps_list_validity() {
+        let value_data = Int32Array::from(vec![1, 300, 2, 3]).data();
+        let value_offsets = Buffer::from(&[0, 2, 3, 4].to_byte_slice());
+        let list_data = ArrayData::builder(DataType::List(Box::new(DataType::Int32)))
+            .len(3)
+            .null_count(1)
+            .null_bit_buffer(Buffer::from([0b101]))
+            .add_buffer(value_offsets)
+            .add_child_data(value_data)
+            .build();
+        let list_array = Arc::new(ListArray::from(list_data)) as ArrayRef;
+
+        let to_type = DataType::List(Box::new(DataType::Int8));
+        let cast_array = cast(&list_array, &to_type).unwrap();
+        assert_eq!(&to_type, cast_array.data_type());
+        // only the null list in the source is null, not the list with an overflow
+        assert_eq!(1, cast_array.null_count());
+        assert!(cast_array.is_valid(0));
+        assert!(cast_array.is_null(1));
+        assert!(cast_array.is_valid(2));
+
+        let array = cast_array.as_any().downcast_ref::<ListArray>().unwrap();
+        assert_eq!(2, array.value_length(0));
+        let values = array.values();
+        let i8arr = values.as_any().downcast_ref::<Int8Array>().unwrap();
+        assert_eq!(1, i8arr.value(0));
+        assert!(i8arr.is_null(1));
+        assert_eq!(1, i8arr.null_count());
+    }
+
+    #[test]
+    fn test_cast_list_utf8_to_list_i32() {
+        let value_data = utf8_array(vec![Some("1"), Some("2"), Some("x")]).data();
+        let value_offsets = Buffer::from(&[0, 2, 3].to_byte_slice());
+        let list_data = ArrayData::builder(DataType::List(Box::new(DataType::Utf8)))
+            .len(2)
+            .add_buffer(value_offsets)
+            .add_child_data(value_data)
+            .build();
+        let list_array = Arc::new(ListArray::from(list_data)) as ArrayRef;
+
+        let cast_array =
+            cast(&list_array, &DataType::List(Box::new(DataType::Int32))).unwrap();
+        assert_eq!(0, cast_array.null_count());
+        let array = cast_array.as_any().downcast_ref::<ListArray>().unwrap();
+        assert_eq!(2, array.value_length(0));
+        assert_eq!(1, array.value_length(1));
+        let values = array.values();
+        let i32arr = values.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(1, i32arr.value(0));
+        assert_eq!(2, i32arr.value(1));
+        assert!(i32arr.is_null(2));
+    }
+
+    #[test]
+    fn test_cast_list_i32_to_list_utf8() {
+        let value_data = Int32Array::from(vec![1, 2, 3]).data();
+        let value_offsets = Buffer::from(&[0, 2, 3].to_byte_slice());
+        let list_data = ArrayData::builder(DataType::List(Box::new(DataType::Int32)))
+            .len(2)
+            .add_buffer(value_offsets.clone())
+            .add_child_data(value_data)
+            .build();
+        let list_array = Arc::new(ListArray::from(list_data)) as ArrayRef;
+
+        let cast_array =
+            cast(&list_array, &DataType::List(Box::new(DataType::Utf8))).unwrap();
+        // the list offsets are kept, the child gets offsets of its own
+        assert_eq!(value_offsets, cast_array.data().buffers()[0]);
+        let array = cast_array.as_any().downcast_ref::<ListArray>().unwrap();
+        assert_eq!(DataType::Utf8, array.value_type());
+        assert_eq!(2, array.value_length(0));
+        assert_eq!(1, array.value_length(1));
+        let values = array.values();
+        let strings = values.as_any().downcast_ref::<BinaryArray>().unwrap();
+        assert_eq!("1", strings.get_string(0));
+        assert_eq!("2", strings.get_string(1));
+        assert_eq!("3", strings.get_string(2));
+    }
+
+    #[test]
+    fn test_cast_list_validate_offsets() {
+        let value_data = Int32Array::from(vec![0, 1, 2, 3]).data();
+        let value_offsets = Buffer::from(&[0, 3, 1, 4].to_byte_slice());
+        let list_data = ArrayData::builder(DataType::List(Box::new(DataType::Int32)))
+            .len(3)
+            .add_buffer(value_offsets)
+            .add_child_data(value_data)
+            .build();
+        let list_array = Arc::new(ListArray::from(list_data)) as ArrayRef;
+        let to_type = DataType::List(Box::new(DataType::Int64));
+
+        let options = CastOptions {
+            validate_offsets: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            ArrowError::InvalidArgumentError(
+                "Invalid list offset 1 at index 2, values have length 4".to_string()
+            ),
+            cast_with_options(&list_array, &to_type, &options)
+                .err()
+                .unwrap()
+        );
+
+        // identity casts check the offsets too
+        let to_type = DataType::List(Box::new(DataType::Int32));
+        assert!(cast_with_options(&list_array, &to_type, &options).is_err());
+        assert!(cast(&list_array, &to_type).is_ok());
+    }
+
+    #[test]
+    fn test_cast_sliced_list_i32_to_list_i64() {
+        let value_data = Int32Array::from(vec![0, 1, 2, 3, 4, 5, 6]).data();
+        let value_offsets = Buffer::from(&[0, 2, 3, 6, 7].to_byte_slice());
+        let list_data = ArrayData::builder(DataType::List(Box::new(DataType::Int32)))
+            .len(4)
+            .null_count(1)
+            .null_bit_buffer(Buffer::from([0b1011]))
+            .add_buffer(value_offsets)
+            .add_child_data(value_data)
+            .build();
+        let list_array = Arc::new(ListArray::from(list_data)) as ArrayRef;
+        let sliced = list_array.slice(1, 2);
+
+        let to_type = DataType::List(Box::new(DataType::Int64));
+        let cast_array = cast(&sliced, &to_type).unwrap();
+        assert_eq!(&to_type, cast_array.data_type());
+        assert_eq!(2, cast_array.len());
+        assert_eq!(0, cast_array.offset());
+        assert!(cast_array.is_valid(0));
+        assert!(cast_array.is_null(1));
+
+        let array = cast_array.as_any().downcast_ref::<ListArray>().unwrap();
+        assert_eq!(0, array.value_offset(0));
+        assert_eq!(1, array.value_length(0));
+        assert_eq!(3, array.value_length(1));
+        // only the values of the 2 slots are cast
+        let values = array.values();
+        let c = values.as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(4, c.len());
+        assert_eq!(
+            vec![2, 3, 4, 5],
+            (0..4).map(|i| c.value(i)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_cast_list_i32_to_list_i32_shares_values() {
+        let value_data = Int32Array::from(vec![0, 1, 2]).data();
+        let value_offsets = Buffer::from(&[0, 2, 3].to_byte_slice());
+        let list_data = ArrayData::builder(DataType::List(Box::new(DataType::Int32)))
+            .len(2)
+            .add_buffer(value_offsets)
+            .add_child_data(value_data.clone())
+            .build();
+        let list_array = Arc::new(ListArray::from(list_data)) as ArrayRef;
+
+        // Int32 values keep their native type, so they are shared and not converted
+        let cast_array =
+            cast(&list_array, &DataType::List(Box::new(DataType::Int32))).unwrap();
+        let child_data = cast_array.data().child_data()[0].clone();
+        assert_eq!(
+            value_data.buffers()[0].raw_data(),
+            child_data.buffers()[0].raw_data()
+        );
+    }
+
+    #[test]
+    fn test_cast_time_to_utf8() {
+        let a = Time32SecondArray::from(vec![Some(45_045), None, Some(86_400), Some(0)]);
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::Utf8).unwrap();
+        let c = b.as_any().downcast_ref::<BinaryArray>().unwrap();
+        assert_eq!("12:30:45", c.get_string(0));
+        assert!(c.is_null(1));
+        assert!(c.is_null(2));
+        assert_eq!("00:00:00", c.get_string(3));
+
+        let a = Time64MicrosecondArray::from(vec![45_045_000_250, 86_399_999_999]);
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::Utf8).unwrap();
+        let c = b.as_any().downcast_ref::<BinaryArray>().unwrap();
+        assert_eq!("12:30:45.000250", c.get_string(0));
+        assert_eq!("23:59:59.999999", c.get_string(1));
+    }
+
+    #[test]
+    fn test_cast_utf8_to_time() {
+        let array = utf8_array(vec![
+            Some("12:30:45"),
+            Some("12:30:45.999"),
+            Some("24:00:00"),
+            Some("noon"),
+            None,
+        ]);
+        let to_type = DataType::Time32(TimeUnit::Second);
+        let b = cast(&array, &to_type).unwrap();
+        assert_eq!(&to_type, b.data_type());
+        let c = b.as_any().downcast_ref::<Time32SecondArray>().unwrap();
+        assert_eq!(45_045, c.value(0));
+        assert_eq!(45_045, c.value(1));
+        assert!(c.is_null(2));
+        assert!(c.is_null(3));
+        assert!(c.is_null(4));
+
+        let array = utf8_array(vec![Some("23:59:59.999999"), Some("00:00:01")]);
+        let to_type = DataType::Time64(TimeUnit::Microsecond);
+        let b = cast(&array, &to_type).unwrap();
+        let c = b.as_any().downcast_ref::<Time64MicrosecondArray>().unwrap();
+        assert_eq!(86_399_999_999, c.value(0));
+        assert_eq!(1_000_000, c.value(1));
+
+        // Time32 only holds seconds and milliseconds, Time64 micro- and nanoseconds
+        for to_type in &[
+            DataType::Time32(TimeUnit::Microsecond),
+            DataType::Time64(TimeUnit::Second),
+        ] {
+            assert_eq!(
+                ArrowError::CastNotSupported {
+                    from: DataType::Utf8,
+                    to: to_type.clone(),
+                },
+                cast(&array, to_type).err().unwrap()
+            );
+        }
+
+        // values that don't parse are null even if the source may not have nulls
+        let array = utf8_array(vec![Some("noon"), Some("00:00:01")]);
+        let options = CastOptions {
+            forbid_source_nulls: true,
+            ..Default::default()
+        };
+        let to_type = DataType::Time32(TimeUnit::Millisecond);
+        let b = cast_with_options(&array, &to_type, &options).unwrap();
+        let c = b.as_any().downcast_ref::<Time32MillisecondArray>().unwrap();
+        assert!(c.is_null(0));
+        assert_eq!(1_000, c.value(1));
+    }
+
+    #[test]
+    fn test_cast_temporal_to_int_shares_buffers() {
+        let values: ArrayRef = Arc::new(Int64Array::from(vec![Some(1), None, Some(3)]));
+        for from_type in &[
+            DataType::Timestamp(TimeUnit::Millisecond),
+            DataType::Date64(DateUnit::Millisecond),
+            DataType::Time64(TimeUnit::Nanosecond),
+        ] {
+            let temporal = reinterpret_array(&values, from_type);
+            let b = cast(&temporal, &DataType::Int64).unwrap();
+            assert_eq!(&DataType::Int64, b.data_type());
+            assert!(shares_buffers(&temporal, &b));
+            let null_bits = |a: &ArrayRef| {
+                a.data_ref().null_bitmap().as_ref().unwrap().bits.raw_data()
+            };
+            assert_eq!(null_bits(&temporal), null_bits(&b));
+            let c = b.as_any().downcast_ref::<Int64Array>().unwrap();
+            assert_eq!(1, c.value(0));
+            assert!(c.is_null(1));
+            assert_eq!(3, c.value(2));
+        }
+
+        let values: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None, Some(3)]));
+        for from_type in &[
+            DataType::Date32(DateUnit::Day),
+            DataType::Time32(TimeUnit::Second),
+        ] {
+            let temporal = reinterpret_array(&values, from_type);
+            let b = cast(&temporal.slice(1, 2), &DataType::Int32).unwrap();
+            assert_eq!(&DataType::Int32, b.data_type());
+            assert!(shares_buffers(&temporal, &b));
+            let c = b.as_any().downcast_ref::<Int32Array>().unwrap();
+            assert!(c.is_null(0));
+            assert_eq!(3, c.value(1));
+        }
+    }
+
     #[test]
     #[should_panic(
-        expected = "Casting from Int32 to Timestamp(Microsecond) not supported"
+        expected = "CastNotSupported { from: Int32, to: Timestamp(Microsecond) }"
     )]
     fn test_cast_list_i32_to_list_timestamp() {
         // Construct a value array
@@ -1712,6 +6025,17 @@ mod tests {
         );
     }
 
+    fn utf8_array(values: Vec<Option<&str>>) -> ArrayRef {
+        let mut b = BinaryBuilder::new(values.len());
+        for v in values {
+            match v {
+                Some(s) => b.append_string(s).unwrap(),
+                None => b.append(false).unwrap(),
+            }
+        }
+        Arc::new(b.finish()) as ArrayRef
+    }
+
     fn get_cast_values<T>(array: &ArrayRef, dt: &DataType) -> Vec<String>
     where
         T: ArrowNumericType,
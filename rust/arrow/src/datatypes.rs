@@ -268,6 +268,9 @@ where
     /// Loads a slice into a SIMD register
     fn load(slice: &[Self::Native]) -> Self::Simd;
 
+    /// Creates a SIMD register with every lane set to `value`
+    fn init(value: Self::Native) -> Self::Simd;
+
     /// Gets the value of a single lane in a SIMD mask
     fn mask_get(mask: &Self::SimdMask, idx: usize) -> bool;
 
@@ -296,6 +299,12 @@ where
     // SIMD version of greater than or equal to
     fn ge(left: Self::Simd, right: Self::Simd) -> Self::SimdMask;
 
+    // SIMD version of the lane-wise minimum
+    fn min(left: Self::Simd, right: Self::Simd) -> Self::Simd;
+
+    // SIMD version of the lane-wise maximum
+    fn max(left: Self::Simd, right: Self::Simd) -> Self::Simd;
+
     /// Writes a SIMD result back to a slice
     fn write(simd_result: Self::Simd, slice: &mut [Self::Native]);
 }
@@ -319,6 +328,10 @@ macro_rules! make_numeric_type {
                 unsafe { Self::Simd::from_slice_unaligned_unchecked(slice) }
             }
 
+            fn init(value: Self::Native) -> Self::Simd {
+                Self::Simd::splat(value)
+            }
+
             fn mask_get(mask: &Self::SimdMask, idx: usize) -> bool {
                 unsafe { mask.extract_unchecked(idx) }
             }
@@ -355,6 +368,14 @@ macro_rules! make_numeric_type {
                 left.ge(right)
             }
 
+            fn min(left: Self::Simd, right: Self::Simd) -> Self::Simd {
+                left.min(right)
+            }
+
+            fn max(left: Self::Simd, right: Self::Simd) -> Self::Simd {
+                left.max(right)
+            }
+
             fn write(simd_result: Self::Simd, slice: &mut [Self::Native]) {
                 unsafe { simd_result.write_to_slice_unaligned_unchecked(slice) };
             }
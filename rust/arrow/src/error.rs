@@ -20,6 +20,8 @@ use std::error::Error;
 
 use csv as csv_crate;
 
+use crate::datatypes::DataType;
+
 /// Many different operations in the `arrow` crate return this error type
 #[derive(Debug, Clone, PartialEq)]
 pub enum ArrowError {
@@ -31,6 +33,11 @@ pub enum ArrowError {
     JsonError(String),
     IoError(String),
     InvalidArgumentError(String),
+    /// The cast kernel can't cast arrays of type `from` to type `to`
+    CastNotSupported {
+        from: DataType,
+        to: DataType,
+    },
 }
 
 impl From<::std::io::Error> for ArrowError {